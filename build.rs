@@ -0,0 +1,148 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Generate the registry of puzzles, by discovering every `src/yYYYY/dayNN` folder.
+/// It avoids the need to manually declare each day module once generated.
+/// The tests of every day and part are generated as well, from the `answers.toml` file of each day.
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let days_path = Path::new(&manifest_dir).join(read_days_path(&manifest_dir));
+
+    println!("cargo:rerun-if-changed=aoc.toml");
+    println!("cargo:rerun-if-changed={}", days_path.display());
+
+    let mut puzzles = Vec::new();
+
+    for (year, year_path) in find_numbered_folders(&days_path, "y", 4) {
+        for (day, day_path) in find_numbered_folders(&year_path, "day", 2) {
+            if day_path.join("mod.rs").exists() {
+                puzzles.push((year as u16, day as u8, day_path));
+            }
+        }
+    }
+
+    puzzles.sort();
+
+    let mut registry = String::new();
+
+    for (year, day, day_path) in puzzles.iter() {
+        registry.push_str(&format!(
+            "#[path = {:?}]\npub mod y{}_day{:02};\n",
+            day_path.join("mod.rs").display().to_string(),
+            year,
+            day
+        ));
+    }
+
+    registry.push_str("\npub const PUZZLES: &[Puzzle] = &[\n");
+    for (year, day, day_path) in puzzles.iter() {
+        let module = format!("y{}_day{:02}", year, day);
+        let include_file = |file_name: &str| {
+            format!(
+                "include_str!({:?})",
+                day_path.join(file_name).display().to_string()
+            )
+        };
+
+        let sample = include_file("sample.txt");
+        let input = include_file("input.txt");
+        let answers = if day_path.join("answers.toml").exists() {
+            format!("Some({})", include_file("answers.toml"))
+        } else {
+            "None".to_string()
+        };
+
+        registry.push_str(&format!(
+            "    Puzzle {{ year: {year}, day: {day}, parts: <{module}::Day as Solution>::PARTS, sample: {sample}, input: {input}, solve: solution::solve::<{module}::Day>, solve_timed: solution::solve_timed::<{module}::Day>, answers: {answers} }},\n"
+        ));
+    }
+    registry.push_str("];\n");
+
+    let mut tests = String::new();
+
+    for (year, day, day_path) in puzzles.iter() {
+        let module = format!("y{}_day{:02}", year, day);
+        let answers = read_answers(&day_path.join("answers.toml"));
+
+        // the number of parts of a day is only known once compiled, see `Solution::PARTS`
+        for part in 1..=2 {
+            for input_name in ["sample", "input"] {
+                let test_name = format!("part{}_{}", part, input_name);
+                let is_known = answers
+                    .get(input_name)
+                    .and_then(|answers| answers.get(format!("part{}", part)))
+                    .and_then(|answer| answer.as_str())
+                    .is_some_and(|answer| !answer.is_empty());
+                let is_slow = answers
+                    .get("slow")
+                    .and_then(|slow| slow.as_array())
+                    .is_some_and(|slow| slow.iter().any(|name| name.as_str() == Some(&test_name)));
+
+                tests.push_str("#[test]\n");
+                if !is_known {
+                    tests.push_str("#[ignore = \"unknown answer\"]\n");
+                } else if is_slow {
+                    tests.push_str("#[ignore = \"slow\"]\n");
+                }
+                tests.push_str(&format!(
+                    "fn {module}_{test_name}() {{\n    if {part} > <super::{module}::Day as Solution>::PARTS {{\n        return;\n    }}\n\n    check_answer({year}, {day}, {part}, {});\n}}\n\n",
+                    input_name == "sample"
+                ));
+            }
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    fs::write(Path::new(&out_dir).join("days.rs"), registry).unwrap();
+    fs::write(Path::new(&out_dir).join("days_tests.rs"), tests).unwrap();
+}
+
+/// Read the `answers.toml` file of a day, which may not exist yet.
+/// A missing (or empty) answer ignores its test, as well as a test listed in the `slow` array of the file.
+fn read_answers(path: &Path) -> toml::Value {
+    let answers = fs::read_to_string(path).unwrap_or_default();
+
+    answers
+        .parse::<toml::Value>()
+        .unwrap_or_else(|error| panic!("Invalid {} file: {}", path.display(), error))
+}
+
+/// Read the folder of the days from the `aoc.toml` project config, `src` by default
+fn read_days_path(manifest_dir: &str) -> String {
+    let config = fs::read_to_string(Path::new(manifest_dir).join("aoc.toml")).unwrap_or_default();
+    let config = config
+        .parse::<toml::Value>()
+        .expect("Invalid aoc.toml file");
+
+    config
+        .get("paths")
+        .and_then(|paths| paths.get("days"))
+        .and_then(|days| days.as_str())
+        .unwrap_or("src")
+        .to_string()
+}
+
+/// Find the sub-folders named by a prefix followed by a number of the given length (e.g. `day01`)
+fn find_numbered_folders(path: &Path, prefix: &str, digits: usize) -> Vec<(u32, PathBuf)> {
+    fs::read_dir(path)
+        .unwrap()
+        .filter_map(|entry| {
+            let entry = entry.unwrap();
+            let name = entry.file_name().into_string().ok()?;
+
+            let is_numbered_folder = entry.file_type().unwrap().is_dir()
+                && name.len() == prefix.len() + digits
+                && name.starts_with(prefix);
+            if !is_numbered_folder {
+                return None;
+            }
+
+            let number = name[prefix.len()..].parse::<u32>().ok()?;
+
+            Some((number, entry.path()))
+        })
+        .collect()
+}
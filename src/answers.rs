@@ -0,0 +1,245 @@
+use serde::Deserialize;
+use std::{fs, path::Path};
+use toml_edit::{Array, Document, Item, Table};
+
+/// Expected answers of a day, stored in the `answers.toml` file of the day folder.
+/// An answer can be omitted, or left empty, if it is not known yet.
+#[derive(Deserialize, Default, Debug)]
+pub struct Answers {
+    #[serde(default)]
+    pub sample: PartAnswers,
+    #[serde(default)]
+    pub input: PartAnswers,
+    /// Answers of the input rejected by the website, so they are never submitted again
+    #[serde(default)]
+    pub wrong: WrongAnswers,
+}
+
+#[derive(Deserialize, Default, Debug)]
+pub struct PartAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+pub struct WrongAnswers {
+    #[serde(default)]
+    pub part1: Vec<String>,
+    #[serde(default)]
+    pub part2: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum Verdict {
+    Correct,
+    Wrong(String),
+    Unknown,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Answers {
+        match toml::from_str(content) {
+            Ok(answers) => answers,
+            Err(error) => panic!("Invalid answers.toml file: {}", error),
+        }
+    }
+
+    /// Read the `answers.toml` file of a day folder, which may not exist yet
+    pub fn read(path: &Path) -> Answers {
+        match fs::read_to_string(path) {
+            Ok(content) => Answers::parse(&content),
+            Err(_) => Answers::default(),
+        }
+    }
+
+    /// Write the answers in the `answers.toml` file, only updating the keys that changed.
+    /// The rest of the file (comments, multi-line answers, etc...) is kept as is.
+    pub fn save(&self, path: &Path) {
+        let content = fs::read_to_string(path).unwrap_or_default();
+        let mut document = content
+            .parse::<Document>()
+            .expect("Invalid answers.toml file");
+
+        for (table_name, part_answers) in [("sample", &self.sample), ("input", &self.input)] {
+            for (key, answer) in [
+                ("part1", &part_answers.part1),
+                ("part2", &part_answers.part2),
+            ] {
+                if let Some(answer) = answer {
+                    let saved_answer = document.get(table_name).and_then(|table| table.get(key));
+
+                    if saved_answer.and_then(Item::as_str) != Some(answer) {
+                        get_table(&mut document, table_name)[key] =
+                            toml_edit::value(answer.as_str());
+                    }
+                }
+            }
+        }
+
+        for (key, wrong_answers) in [("part1", &self.wrong.part1), ("part2", &self.wrong.part2)] {
+            let saved_answers = document
+                .get("wrong")
+                .and_then(|table| table.get(key))
+                .and_then(Item::as_array)
+                .map(|answers| {
+                    answers
+                        .iter()
+                        .filter_map(|answer| answer.as_str())
+                        .collect()
+                })
+                .unwrap_or_else(Vec::new);
+
+            if !wrong_answers.is_empty() && saved_answers != *wrong_answers {
+                get_table(&mut document, "wrong")[key] =
+                    Item::Value(Array::from_iter(wrong_answers).into());
+            }
+        }
+
+        fs::write(path, document.to_string()).unwrap();
+    }
+
+    pub fn get_expected_answer(&self, part: u8, use_sample: bool) -> Option<&str> {
+        let part_answers = if use_sample {
+            &self.sample
+        } else {
+            &self.input
+        };
+
+        let expected_answer = match part {
+            1 => &part_answers.part1,
+            2 => &part_answers.part2,
+            _ => panic!("Invalid part number"),
+        };
+
+        expected_answer
+            .as_deref()
+            .filter(|expected_answer| !expected_answer.is_empty())
+    }
+
+    pub fn set_input_answer(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.input.part1 = Some(answer.to_string()),
+            2 => self.input.part2 = Some(answer.to_string()),
+            _ => panic!("Invalid part number"),
+        }
+    }
+
+    pub fn get_wrong_answers(&self, part: u8) -> &[String] {
+        match part {
+            1 => &self.wrong.part1,
+            2 => &self.wrong.part2,
+            _ => panic!("Invalid part number"),
+        }
+    }
+
+    pub fn add_wrong_answer(&mut self, part: u8, answer: &str) {
+        let wrong_answers = match part {
+            1 => &mut self.wrong.part1,
+            2 => &mut self.wrong.part2,
+            _ => panic!("Invalid part number"),
+        };
+
+        wrong_answers.push(answer.to_string());
+    }
+
+    pub fn verify(&self, part: u8, use_sample: bool, answer: &str) -> Verdict {
+        match self.get_expected_answer(part, use_sample) {
+            Some(expected_answer) if expected_answer == answer => Verdict::Correct,
+            Some(expected_answer) => Verdict::Wrong(expected_answer.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// Get a table of the document, created at the end of the file if missing
+fn get_table<'a>(document: &'a mut Document, name: &str) -> &'a mut Table {
+    if !document.contains_key(name) {
+        // the comments ending the file belong to the last table, they stay before the new one
+        let trailing = document.trailing().as_str().unwrap_or_default().to_string();
+        document.set_trailing("");
+
+        let mut table = Table::new();
+        if !document.is_empty() {
+            table.decor_mut().set_prefix(format!("{}\n", trailing));
+        }
+
+        document.insert(name, Item::Table(table));
+    }
+
+    document[name]
+        .as_table_mut()
+        .unwrap_or_else(|| panic!("Invalid answers.toml file: {} is not a table", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    const DAY_10_ANSWERS: &str = "# Expected answers of y2022/day10, verified after each run
+[sample]
+part1 = \"13140\"
+part2 = '''
+##..
+###.'''
+
+[input]
+part1 = \"\"
+part2 = \"\"
+";
+
+    fn save_answers(name: &str, content: &str, update: fn(&mut Answers)) -> String {
+        let path = env::temp_dir().join(format!("aoc-answers-{}-{}.toml", name, process::id()));
+        fs::write(&path, content).unwrap();
+
+        let mut answers = Answers::read(&path);
+        update(&mut answers);
+        answers.save(&path);
+
+        let saved_content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        saved_content
+    }
+
+    #[test]
+    fn save_only_changed_answer() {
+        let content = save_answers("answer", DAY_10_ANSWERS, |answers| {
+            answers.set_input_answer(1, "13740")
+        });
+
+        assert_eq!(
+            content,
+            DAY_10_ANSWERS.replace("[input]\npart1 = \"\"", "[input]\npart1 = \"13740\"")
+        );
+        assert_eq!(
+            Answers::parse(&content).get_expected_answer(2, true),
+            Some("##..\n###.")
+        );
+        assert_eq!(Answers::parse(&content).get_expected_answer(2, false), None);
+    }
+
+    #[test]
+    fn save_wrong_answers() {
+        let content = save_answers("wrong", DAY_10_ANSWERS, |answers| {
+            answers.add_wrong_answer(2, "ABCDEFGH");
+            answers.add_wrong_answer(2, "ABCDEFGI");
+        });
+
+        assert!(content.starts_with(DAY_10_ANSWERS));
+        assert_eq!(
+            Answers::parse(&content).get_wrong_answers(2),
+            ["ABCDEFGH", "ABCDEFGI"]
+        );
+    }
+
+    #[test]
+    fn create_missing_file() {
+        let content = save_answers("missing", "", |answers| answers.set_input_answer(2, "42"));
+
+        assert_eq!(
+            Answers::parse(&content).get_expected_answer(2, false),
+            Some("42")
+        );
+    }
+}
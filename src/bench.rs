@@ -0,0 +1,276 @@
+use crate::{
+    config,
+    days::Puzzle,
+    history::{self, HistoryRecord, Measurement},
+    puzzle::{check_part, display_info, get_puzzle, read_env_variable, read_use_sample},
+    solution::Timings,
+};
+use colored::Colorize;
+use std::{collections::BTreeMap, process, time::Duration};
+
+/// Statistics of the durations of all measured runs
+#[derive(Debug)]
+struct Statistics {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    stddev: Duration,
+}
+
+/// Evolution of the duration of a puzzle, compared to the threshold
+#[derive(Debug, PartialEq, Eq)]
+enum Trend {
+    Slower,
+    Faster,
+    Stable,
+}
+
+/// Run a puzzle multiple times, then display statistics on the parse, solve and total durations.
+/// Warm-up runs are executed first and ignored in the statistics.
+pub fn run(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    use_sample: Option<bool>,
+    runs: u32,
+    warmup: u32,
+    use_history: bool,
+) {
+    let year = config::get_year(year);
+    let day = day.unwrap_or_else(|| read_env_variable("DAY"));
+    let part = part.unwrap_or_else(|| read_env_variable("PART"));
+    let use_sample = read_use_sample(use_sample);
+
+    let puzzle = get_puzzle(year, day);
+    check_part(puzzle, part);
+
+    display_info(year, day, part, use_sample);
+
+    for _ in 0..warmup {
+        solve_timed(puzzle, part, use_sample);
+    }
+
+    let mut answer = String::new();
+    let mut all_timings = Vec::new();
+
+    for _ in 0..runs {
+        let (run_answer, timings) = solve_timed(puzzle, part, use_sample);
+
+        answer = run_answer;
+        all_timings.push(timings);
+    }
+
+    println!("{}", answer);
+    println!(
+        "{}",
+        format!("{} warm-up runs, {} measured runs", warmup, runs).italic()
+    );
+    println!();
+
+    let parse_durations = get_durations(&all_timings, |timings| timings.parse);
+    let solve_durations = get_durations(&all_timings, |timings| timings.solve);
+    let total_durations = get_durations(&all_timings, |timings| timings.parse + timings.solve);
+
+    println!(
+        "{:<6} | {:>12} | {:>12} | {:>12} | {:>12}",
+        "", "Min", "Median", "Mean", "Std dev"
+    );
+    println!("{}", "-".repeat(66));
+
+    display_statistics("Parse", &parse_durations);
+    display_statistics("Solve", &solve_durations);
+    display_statistics("Total", &total_durations);
+
+    if use_history {
+        if let Some(statistics) = get_statistics(&total_durations) {
+            let input_name = if use_sample { "sample" } else { "input" };
+            let duration_ms = statistics.median.as_secs_f64() * 1000.0;
+
+            history::append(&[HistoryRecord::new(
+                year,
+                day,
+                part,
+                input_name,
+                Measurement::Bench,
+                duration_ms,
+            )]);
+        }
+    }
+}
+
+/// Compare the last recorded duration of each year, day and part with the previous one measured the same way.
+/// Exit with an error code if any of them got slower than the given threshold (in percent).
+pub fn compare(threshold: f64) {
+    type PuzzleKey = (u16, u8, u8, String, Measurement);
+    let mut records_by_puzzle: BTreeMap<PuzzleKey, Vec<HistoryRecord>> = BTreeMap::new();
+
+    for record in history::read() {
+        let key = (
+            record.year,
+            record.day,
+            record.part,
+            record.input.clone(),
+            record.measurement,
+        );
+        records_by_puzzle.entry(key).or_default().push(record);
+    }
+
+    println!(
+        "{:>4} | {:>4} | {:>4} | {:<6} | {:<5} | {:>12} | {:>12} | Change",
+        "Year", "Day", "Part", "Input", "Kind", "Previous", "Latest"
+    );
+    println!("{}", "-".repeat(81));
+
+    let mut total_slower = 0;
+
+    for ((year, day, part, input, measurement), records) in records_by_puzzle.iter() {
+        let (previous, latest) = match records[..] {
+            [.., ref previous, ref latest] => (previous, latest),
+            _ => continue,
+        };
+
+        let change = get_change(previous.duration_ms, latest.duration_ms);
+        let change_text = match change {
+            Some(change) => format!("{:+.1}%", change),
+            None => String::from("n/a"),
+        };
+        let change_text = match get_trend(change, threshold) {
+            Trend::Slower => {
+                total_slower += 1;
+                format!("{} slower", change_text).red()
+            }
+            Trend::Faster => format!("{} faster", change_text).green(),
+            Trend::Stable => change_text.normal(),
+        };
+
+        println!(
+            "{:>4} | {:>4} | {:>4} | {:<6} | {:<5} | {:>12} | {:>12} | {}",
+            year,
+            day,
+            part,
+            input,
+            measurement.as_str(),
+            format!("{:.3}ms", previous.duration_ms),
+            format!("{:.3}ms", latest.duration_ms),
+            change_text
+        );
+    }
+
+    println!();
+    println!(
+        "{} slower than the threshold of {}%",
+        total_slower.to_string().red(),
+        threshold
+    );
+
+    if total_slower > 0 {
+        process::exit(1);
+    }
+}
+
+/// Change of the duration in percent, unknown if the previous one was too short to be measured (`0.000ms`)
+fn get_change(previous_ms: f64, latest_ms: f64) -> Option<f64> {
+    if previous_ms <= 0.0 {
+        return None;
+    }
+
+    Some((latest_ms - previous_ms) / previous_ms * 100.0)
+}
+
+fn get_trend(change: Option<f64>, threshold: f64) -> Trend {
+    match change {
+        Some(change) if change > threshold => Trend::Slower,
+        Some(change) if change < -threshold => Trend::Faster,
+        _ => Trend::Stable,
+    }
+}
+
+/// Solve the puzzle, exiting with an error code if the input cannot be parsed
+fn solve_timed(puzzle: &Puzzle, part: u8, use_sample: bool) -> (String, Timings) {
+    match (puzzle.solve_timed)(part, puzzle.get_input(use_sample), use_sample) {
+        Ok(result) => result,
+        Err(error) => {
+            println!("{}", error.with_day(puzzle.day).render().red());
+            process::exit(1);
+        }
+    }
+}
+
+fn get_durations(all_timings: &[Timings], get_duration: fn(&Timings) -> Duration) -> Vec<Duration> {
+    all_timings.iter().map(get_duration).collect()
+}
+
+fn display_statistics(name: &str, durations: &[Duration]) {
+    let statistics = match get_statistics(durations) {
+        Some(statistics) => statistics,
+        None => return,
+    };
+
+    println!(
+        "{:<6} | {:>12} | {:>12} | {:>12} | {:>12}",
+        name,
+        format_duration(statistics.min),
+        format_duration(statistics.median),
+        format_duration(statistics.mean),
+        format_duration(statistics.stddev)
+    );
+}
+
+fn get_statistics(durations: &[Duration]) -> Option<Statistics> {
+    if durations.is_empty() {
+        return None;
+    }
+
+    let mut sorted_durations = durations.to_vec();
+    sorted_durations.sort();
+
+    let length = sorted_durations.len();
+    let median = if length.is_multiple_of(2) {
+        (sorted_durations[length / 2 - 1] + sorted_durations[length / 2]) / 2
+    } else {
+        sorted_durations[length / 2]
+    };
+
+    let seconds = durations
+        .iter()
+        .map(|duration| duration.as_secs_f64())
+        .collect::<Vec<_>>();
+
+    let mean = seconds.iter().sum::<f64>() / length as f64;
+    let variance = seconds
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / length as f64;
+
+    Some(Statistics {
+        min: sorted_durations[0],
+        median,
+        mean: Duration::from_secs_f64(mean),
+        stddev: Duration::from_secs_f64(variance.sqrt()),
+    })
+}
+
+/// Display a duration in milliseconds, with a microsecond precision
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_changes_beyond_threshold() {
+        assert_eq!(get_trend(get_change(10.0, 11.5), 10.0), Trend::Slower);
+        assert_eq!(get_trend(get_change(10.0, 8.5), 10.0), Trend::Faster);
+        assert_eq!(get_trend(get_change(10.0, 10.9), 10.0), Trend::Stable);
+        assert_eq!(get_trend(get_change(10.0, 11.0), 10.0), Trend::Stable);
+    }
+
+    #[test]
+    fn ignore_unmeasured_previous_duration() {
+        assert_eq!(get_change(0.0, 0.001), None);
+        assert_eq!(get_trend(get_change(0.0, 0.001), 10.0), Trend::Stable);
+    }
+}
@@ -0,0 +1,263 @@
+use colored::Colorize;
+use similar::TextDiff;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Change of a single file, planned before being written to the disk
+#[derive(Debug)]
+struct FileChange {
+    path: PathBuf,
+    /// Content of the file before the change, or nothing if the file is created
+    before: Option<String>,
+    /// Content of the file after the change, or nothing if the file is deleted
+    after: Option<String>,
+}
+
+/// List of file changes, which can be previewed as a diff before being applied all at once.
+/// If any of the changes fails, all the changes already applied are rolled back.
+#[derive(Default, Debug)]
+pub struct ChangePlan {
+    changes: Vec<FileChange>,
+}
+
+impl ChangePlan {
+    pub fn create(&mut self, path: impl AsRef<Path>, content: String) {
+        self.changes.push(FileChange {
+            path: path.as_ref().to_path_buf(),
+            before: None,
+            after: Some(content),
+        });
+    }
+
+    pub fn modify(&mut self, path: impl AsRef<Path>, content: String) -> io::Result<()> {
+        let before = fs::read_to_string(&path)?;
+
+        self.changes.push(FileChange {
+            path: path.as_ref().to_path_buf(),
+            before: Some(before),
+            after: Some(content),
+        });
+
+        Ok(())
+    }
+
+    pub fn delete(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        let before = fs::read_to_string(&path)?;
+
+        self.changes.push(FileChange {
+            path: path.as_ref().to_path_buf(),
+            before: Some(before),
+            after: None,
+        });
+
+        Ok(())
+    }
+
+    /// Write a file, replacing the content of a change already planned for the same file
+    pub fn write(&mut self, path: impl AsRef<Path>, content: String) -> io::Result<()> {
+        let path = path.as_ref();
+
+        match self.changes.iter_mut().find(|change| change.path == path) {
+            Some(change) => change.after = Some(content),
+            None if path.exists() => self.modify(path, content)?,
+            None => self.create(path, content),
+        }
+
+        Ok(())
+    }
+
+    /// Display a unified diff of every file that would be created, modified or deleted
+    pub fn display_diff(&self) {
+        for change in self.changes.iter() {
+            let path = change.path.display().to_string();
+            let (old_header, before) = match &change.before {
+                Some(before) => (path.clone(), before.as_str()),
+                None => (String::from("/dev/null"), ""),
+            };
+            let (new_header, after) = match &change.after {
+                Some(after) => (path.clone(), after.as_str()),
+                None => (String::from("/dev/null"), ""),
+            };
+
+            let diff = TextDiff::from_lines(before, after);
+            let unified_diff = diff
+                .unified_diff()
+                .header(&old_header, &new_header)
+                .to_string();
+
+            for line in unified_diff.lines() {
+                match line {
+                    line if line.starts_with("+++") || line.starts_with("---") => {
+                        println!("{}", line.bold())
+                    }
+                    line if line.starts_with('+') => println!("{}", line.green()),
+                    line if line.starts_with('-') => println!("{}", line.red()),
+                    line if line.starts_with("@@") => println!("{}", line.cyan()),
+                    line => println!("{}", line),
+                }
+            }
+        }
+    }
+
+    /// Apply all the changes, or none of them if any change fails
+    pub fn apply(&self) -> Result<(), String> {
+        let mut applied_changes = Vec::new();
+        let mut created_folders = Vec::new();
+
+        for change in self.changes.iter() {
+            match apply_change(change, &mut created_folders) {
+                Ok(()) => applied_changes.push(change),
+                Err(error) => {
+                    rollback(&applied_changes, &created_folders);
+
+                    return Err(format!(
+                        "cannot write {}: {}. All changes have been rolled back.",
+                        change.path.display(),
+                        error
+                    ));
+                }
+            }
+        }
+
+        remove_empty_folders(&applied_changes);
+
+        Ok(())
+    }
+}
+
+fn apply_change(change: &FileChange, created_folders: &mut Vec<PathBuf>) -> io::Result<()> {
+    let after = match &change.after {
+        Some(after) => after,
+        None => return fs::remove_file(&change.path),
+    };
+
+    if let Some(parent) = change.path.parent() {
+        let missing_folders = parent
+            .ancestors()
+            .take_while(|folder| !folder.as_os_str().is_empty() && !folder.exists())
+            .map(|folder| folder.to_path_buf())
+            .collect::<Vec<_>>();
+
+        fs::create_dir_all(parent)?;
+
+        // outermost folders first, so they are removed last
+        created_folders.extend(missing_folders.into_iter().rev());
+    }
+
+    fs::write(&change.path, after)
+}
+
+/// Remove the folders left empty by deleted files, from the deepest to the outermost
+fn remove_empty_folders(applied_changes: &[&FileChange]) {
+    let mut folders = applied_changes
+        .iter()
+        .filter(|change| change.after.is_none())
+        .flat_map(|change| change.path.ancestors().skip(1))
+        .filter(|folder| !folder.as_os_str().is_empty())
+        .collect::<Vec<_>>();
+
+    folders.sort_by_key(|folder| std::cmp::Reverse(folder.components().count()));
+    folders.dedup();
+
+    for folder in folders {
+        // only empty folders can be removed
+        let _ = fs::remove_dir(folder);
+    }
+}
+
+fn rollback(applied_changes: &[&FileChange], created_folders: &[PathBuf]) {
+    for change in applied_changes.iter().rev() {
+        let _ = match &change.before {
+            Some(before) => fs::create_dir_all(change.path.parent().unwrap())
+                .and_then(|_| fs::write(&change.path, before)),
+            None => fs::remove_file(&change.path),
+        };
+    }
+
+    for folder in created_folders.iter().rev() {
+        let _ = fs::remove_dir(folder);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    fn create_temp_folder(name: &str) -> PathBuf {
+        let folder = env::temp_dir().join(format!("aoc-change-plan-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&folder);
+        fs::create_dir_all(&folder).unwrap();
+
+        folder
+    }
+
+    #[test]
+    fn apply_all_changes() {
+        let folder = create_temp_folder("apply");
+        fs::write(folder.join("modified.txt"), "before").unwrap();
+        fs::create_dir_all(folder.join("old")).unwrap();
+        fs::write(folder.join("old/deleted.txt"), "deleted").unwrap();
+
+        let mut plan = ChangePlan::default();
+        plan.write(folder.join("modified.txt"), String::from("after"))
+            .unwrap();
+        plan.create(
+            folder.join("new/day01/created.txt"),
+            String::from("created"),
+        );
+        plan.delete(folder.join("old/deleted.txt")).unwrap();
+
+        plan.apply().unwrap();
+
+        assert_eq!(
+            fs::read_to_string(folder.join("modified.txt")).unwrap(),
+            "after"
+        );
+        assert_eq!(
+            fs::read_to_string(folder.join("new/day01/created.txt")).unwrap(),
+            "created"
+        );
+        assert!(!folder.join("old").exists());
+
+        fs::remove_dir_all(&folder).unwrap();
+    }
+
+    #[test]
+    fn rollback_all_changes_when_a_write_fails() {
+        let folder = create_temp_folder("rollback");
+        fs::write(folder.join("modified.txt"), "before").unwrap();
+        fs::write(folder.join("deleted.txt"), "deleted").unwrap();
+        fs::create_dir_all(folder.join("existing_folder")).unwrap();
+
+        let mut plan = ChangePlan::default();
+        plan.write(folder.join("modified.txt"), String::from("after"))
+            .unwrap();
+        plan.create(
+            folder.join("new/day01/created.txt"),
+            String::from("created"),
+        );
+        plan.delete(folder.join("deleted.txt")).unwrap();
+        // a folder cannot be written as a file
+        plan.create(folder.join("existing_folder"), String::from("fails"));
+
+        let error = plan.apply().unwrap_err();
+
+        assert!(error.contains("All changes have been rolled back"));
+        assert_eq!(
+            fs::read_to_string(folder.join("modified.txt")).unwrap(),
+            "before"
+        );
+        assert_eq!(
+            fs::read_to_string(folder.join("deleted.txt")).unwrap(),
+            "deleted"
+        );
+        assert!(!folder.join("new/day01/created.txt").exists());
+        assert!(!folder.join("new").exists());
+        assert!(folder.join("existing_folder").is_dir());
+
+        fs::remove_dir_all(&folder).unwrap();
+    }
+}
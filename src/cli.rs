@@ -1,14 +1,148 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
+    /// Year of the puzzles, defaults to the YEAR env variable (all years for run-all)
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(2015..))]
+    pub year: Option<u16>,
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
 
 #[derive(Parser, Debug)]
 pub enum Commands {
+    /// Run the puzzle of the given day and part
+    Run {
+        /// Day of the puzzle, defaults to the DAY env variable
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part of the puzzle, defaults to the PART env variable
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Use the sample file instead of the input file (`--sample=false` to force the input file),
+        /// defaults to the USE_SAMPLE env variable, or to the input file if not set
+        #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+        sample: Option<bool>,
+        /// Path of an input file to use instead of the embedded ones, or `-` to read from stdin
+        #[arg(long, conflicts_with = "sample")]
+        input: Option<String>,
+        /// Output format of the run
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Append the duration to the history file, to track performance regressions
+        #[arg(long)]
+        history: bool,
+    },
+    /// Benchmark the puzzle of the given day and part, using multiple runs
+    #[command(args_conflicts_with_subcommands = true)]
+    Bench {
+        #[command(subcommand)]
+        command: Option<BenchCommands>,
+        /// Day of the puzzle, defaults to the DAY env variable
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part of the puzzle, defaults to the PART env variable
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Use the sample file instead of the input file (`--sample=false` to force the input file),
+        /// defaults to the USE_SAMPLE env variable, or to the input file if not set
+        #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+        sample: Option<bool>,
+        /// Number of measured runs
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Number of runs executed before measuring, ignored in the statistics
+        #[arg(long, default_value_t = 1)]
+        warmup: u32,
+        /// Append the median duration to the history file, to track performance regressions
+        #[arg(long)]
+        history: bool,
+    },
+    /// Run every day and part, of every year unless one is given, then print a summary table
+    RunAll {
+        /// Input files to run the puzzles with
+        #[arg(long, value_enum, default_value_t = Inputs::Both)]
+        inputs: Inputs,
+        /// Output format of the runs
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Append the durations to the history file, to track performance regressions
+        #[arg(long)]
+        history: bool,
+    },
     /// Generate folder for the given day
-    Generate { day: Option<u8> },
+    Generate {
+        day: Option<u8>,
+        /// Name of the template to generate the folder from (blank, grid, graph, simulation or a user template),
+        /// defaults to the template of the project config
+        #[arg(long)]
+        template: Option<String>,
+        /// Download the input of the day once generated
+        #[arg(long)]
+        fetch: bool,
+        /// Download the puzzle page to extract the sample and its answers
+        #[arg(long)]
+        fetch_sample: bool,
+        /// Path of a saved puzzle page to extract the sample and its answers from
+        #[arg(long, conflicts_with = "fetch_sample")]
+        page: Option<String>,
+        /// Display the diff of every file that would be created or modified, without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Remove the folder of the given day, the reverse of generate
+    Remove {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Remove the folder even if its files have changed from the template
+        #[arg(long)]
+        force: bool,
+        /// Display the diff of every file that would be deleted or modified, without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Submit the answer of the given day and part, computed using the real input
+    Submit {
+        /// Day of the puzzle, defaults to the DAY env variable
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part of the puzzle, defaults to the PART env variable
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Download the input of the given day, if not already present
+    Fetch {
+        /// Day of the puzzle, defaults to the DAY env variable
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub enum BenchCommands {
+    /// Compare the last recorded duration of each day and part with the previous one
+    Compare {
+        /// Percentage of the previous duration from which a run is flagged as slower
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum Inputs {
+    /// Only the sample.txt files
+    Sample,
+    /// Only the input.txt files
+    Real,
+    /// Both the sample.txt and input.txt files
+    Both,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable text
+    Text,
+    /// One JSON object per line, for each day and part
+    Json,
 }
@@ -0,0 +1,139 @@
+use crate::puzzle::read_env_variable;
+use serde::Deserialize;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    sync::OnceLock,
+};
+
+/// Name of the project config file, which also marks the root of the project
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Project config, read from the `aoc.toml` file at the root of the project.
+/// Every value is optional, and falls back to the default layout of the project.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub paths: Paths,
+    pub defaults: Defaults,
+    pub display: Display,
+    /// Folder the command was run from, before moving to the root of the project
+    #[serde(skip)]
+    pub working_dir: PathBuf,
+}
+
+/// Paths of the project, relative to its root
+#[derive(Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    /// Folder containing the `yYYYY/dayNN` folders
+    pub days: PathBuf,
+    /// Folder containing the built-in templates
+    pub templates: PathBuf,
+    /// Folder containing your own templates, which take precedence over the built-in ones
+    pub user_templates: Option<PathBuf>,
+    /// Cargo config file holding the env variables of the default puzzle
+    pub env_config: PathBuf,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Paths {
+            days: PathBuf::from("src"),
+            templates: PathBuf::from("templates"),
+            user_templates: None,
+            env_config: PathBuf::from(".cargo/config.toml"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Defaults {
+    /// Year used when neither the `--year` option nor the `YEAR` env variable is set
+    pub year: u16,
+    /// Template used when the `--template` option is not set
+    pub template: String,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Defaults {
+            year: 2022,
+            template: String::from("blank"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Display {
+    /// Colorize the output of the commands
+    pub colors: bool,
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Display { colors: true }
+    }
+}
+
+/// Locate the root of the project from the current folder, read its config,
+/// then move to the root so that every path of the project is resolved from there
+pub fn load() {
+    let working_dir = env::current_dir().unwrap();
+
+    let root = working_dir
+        .ancestors()
+        .find(|folder| folder.join(CONFIG_FILE_NAME).exists());
+
+    let mut config = match root {
+        Some(root) => read_config(&root.join(CONFIG_FILE_NAME)),
+        None => Config::default(),
+    };
+
+    if let Some(root) = root {
+        env::set_current_dir(root).unwrap();
+    }
+
+    if !config.display.colors {
+        colored::control::set_override(false);
+    }
+
+    config.working_dir = working_dir;
+
+    CONFIG.set(config).unwrap();
+}
+
+fn read_config(path: &Path) -> Config {
+    let content = fs::read_to_string(path).unwrap();
+
+    match toml::from_str(&content) {
+        Ok(config) => config,
+        Err(error) => {
+            println!("Invalid {} file: {}", path.display(), error);
+            process::exit(1);
+        }
+    }
+}
+
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+/// Resolve a path given by the user, relative to the folder the command was run from
+pub fn resolve_user_path(path: &str) -> PathBuf {
+    get().working_dir.join(path)
+}
+
+/// Year given as an option, else the `YEAR` env variable, else the default year of the config
+pub fn get_year(year: Option<u16>) -> u16 {
+    match year {
+        Some(year) => year,
+        None if env::var("YEAR").is_ok() => read_env_variable("YEAR"),
+        None => get().defaults.year,
+    }
+}
@@ -0,0 +1,103 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Value tracked along a simulation, like the height of a tower
+pub type Metric = i64;
+
+/// Repeating part of a simulation, found once a state has been seen twice
+#[derive(Debug)]
+pub struct Cycle {
+    /// Number of steps before the first cycle starts
+    pub start: usize,
+    /// Number of steps of a cycle
+    pub length: usize,
+    /// Metric after each step until the end of the first cycle, starting with the initial one
+    metrics: Vec<Metric>,
+}
+
+impl Cycle {
+    /// Metric after the given number of steps, every cycle adding the same amount to it
+    pub fn extrapolate(&self, steps: usize) -> Metric {
+        if steps < self.metrics.len() {
+            return self.metrics[steps];
+        }
+
+        let cycle_start = self.metrics[self.start];
+        let cycle_end = self.metrics[self.start + self.length];
+
+        let cycles = (steps - self.start) / self.length;
+        let remaining_steps = (steps - self.start) % self.length;
+
+        self.metrics[self.start + remaining_steps] + (cycle_end - cycle_start) * cycles as Metric
+    }
+}
+
+/// Run a simulation until it reaches a state seen before,
+/// `next` doing a single step and returning the new state along with the new metric
+pub fn find_cycle<S: Eq + Hash>(
+    initial: (S, Metric),
+    mut next: impl FnMut() -> (S, Metric),
+) -> Cycle {
+    let (initial_state, initial_metric) = initial;
+
+    let mut seen_states = HashMap::from([(initial_state, 0)]);
+    let mut metrics = vec![initial_metric];
+
+    loop {
+        let (state, metric) = next();
+        metrics.push(metric);
+
+        let step = metrics.len() - 1;
+
+        if let Some(start) = seen_states.insert(state, step) {
+            return Cycle {
+                start,
+                length: step - start,
+                metrics,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// States 0, 1, 2, 3, 4, 2, 3, 4..., the metric being the sum of the states
+    fn simulate(steps: usize) -> Metric {
+        let mut state = 0;
+        let mut sum = 0;
+
+        for _ in 0..steps {
+            state = next_state(state);
+            sum += state;
+        }
+
+        sum
+    }
+
+    fn next_state(state: Metric) -> Metric {
+        match state {
+            4 => 2,
+            _ => state + 1,
+        }
+    }
+
+    #[test]
+    fn find_start_and_length() {
+        let mut state = 0;
+        let mut sum = 0;
+
+        let cycle = find_cycle((state, sum), || {
+            state = next_state(state);
+            sum += state;
+
+            (state, sum)
+        });
+
+        assert_eq!((cycle.start, cycle.length), (2, 3));
+
+        for steps in [0, 3, 5, 6, 100, 1_000_001] {
+            assert_eq!(cycle.extrapolate(steps), simulate(steps));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    answers::Answers,
+    config,
+    parse_error::ParseError,
+    solution::{self, Solution, TimedAnswer},
+};
+use std::path::PathBuf;
+
+/// Puzzle of a single day, registered by the build script for each `src/yYYYY/dayNN` folder
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub parts: u8,
+    /// Content of the `sample.txt` and `input.txt` files, embedded in the binary
+    pub sample: &'static str,
+    pub input: &'static str,
+    /// Solve a part of the given input, parsed as the sample or as a real input
+    pub solve: fn(part: u8, input: &str, use_sample: bool) -> Result<String, ParseError>,
+    pub solve_timed: fn(part: u8, input: &str, use_sample: bool) -> Result<TimedAnswer, ParseError>,
+    /// Content of the `answers.toml` file, if the day folder contains one
+    pub answers: Option<&'static str>,
+}
+
+impl Puzzle {
+    pub fn get_input(&self, use_sample: bool) -> &'static str {
+        if use_sample {
+            self.sample
+        } else {
+            self.input
+        }
+    }
+
+    /// Whether the puzzle has the given part (the last day only has a single part)
+    pub fn has_part(&self, part: u8) -> bool {
+        (1..=self.parts).contains(&part)
+    }
+
+    pub fn get_answers(&self) -> Answers {
+        self.answers.map(Answers::parse).unwrap_or_default()
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+pub fn find_puzzle(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+/// Folder of the given day, like `src/y2022/day01`
+pub fn get_day_path(year: u16, day: u8) -> PathBuf {
+    config::get()
+        .paths
+        .days
+        .join(format!("y{}", year))
+        .join(format!("day{:02}", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_puzzle;
+    use crate::solution::Solution;
+
+    /// Assert the answer of a part against the one stored in the `answers.toml` file of the day
+    fn check_answer(year: u16, day: u8, part: u8, use_sample: bool) {
+        let puzzle = find_puzzle(year, day).unwrap();
+        let answers = puzzle.get_answers();
+        let expected_answer = answers
+            .get_expected_answer(part, use_sample)
+            .expect("Unknown answer, add it to the answers.toml file of the day");
+
+        assert_eq!(
+            (puzzle.solve)(part, puzzle.get_input(use_sample), use_sample).unwrap(),
+            expected_answer
+        );
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/days_tests.rs"));
+}
@@ -0,0 +1,228 @@
+use crate::{config, days, puzzle::read_env_variable};
+use std::{env, fs, path::Path, process};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE_PATH: &str = ".aoc_session";
+const USER_AGENT: &str = "github.com/Odonno/advent-of-code-2022";
+
+/// Advent of Code website (or a local server), along with the session token used to authenticate
+pub struct Website {
+    pub base_url: String,
+    /// Session token, required by every request
+    pub session: Option<String>,
+}
+
+impl Website {
+    /// Website configured by the `AOC_BASE_URL` and `AOC_SESSION` env variables, or by the `.aoc_session` file
+    pub fn from_env() -> Website {
+        Website {
+            base_url: read_base_url(),
+            session: read_session(),
+        }
+    }
+
+    pub fn download_input(&self, year: u16, day: u8) -> Result<String, String> {
+        self.get_page(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Get a page of the website, authenticated with the session token
+    pub fn get_page(&self, path: &str) -> Result<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let session = self.get_session()?;
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+
+        read_response(response)
+    }
+
+    /// Post a form to the website, authenticated with the session token
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let session = self.get_session()?;
+
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(form);
+
+        read_response(response)
+    }
+
+    fn get_session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            format!(
+                "missing session token, set the AOC_SESSION env variable or create a {} file",
+                SESSION_FILE_PATH
+            )
+        })
+    }
+}
+
+/// Download the input of the given day, in the `src/yYYYY/dayNN/input.txt` file.
+/// The input is only downloaded once, and never fetched again if already present.
+pub fn run(year: Option<u16>, day: Option<u8>) {
+    let year = config::get_year(year);
+    let day = day.unwrap_or_else(|| read_env_variable("DAY"));
+
+    fetch_input(&Website::from_env(), year, day);
+}
+
+fn fetch_input(website: &Website, year: u16, day: u8) {
+    let day_path = days::get_day_path(year, day);
+
+    if !day_path.exists() {
+        println!(
+            "Folder {} does not exist. Generate it first using the script.",
+            day_path.display()
+        );
+        process::exit(1);
+    }
+
+    let input_path = day_path.join("input.txt");
+
+    match download_missing_input(website, &input_path, year, day) {
+        Ok(true) => println!("Input of day {} saved in {}", day, input_path.display()),
+        Ok(false) => println!(
+            "Input of day {} already fetched in {}",
+            day,
+            input_path.display()
+        ),
+        Err(error) => {
+            println!("Cannot fetch input of day {}: {}", day, error);
+            process::exit(1);
+        }
+    }
+}
+
+/// Download the input in the given file, unless it is already present.
+/// Return whether the input was downloaded.
+fn download_missing_input(
+    website: &Website,
+    input_path: &Path,
+    year: u16,
+    day: u8,
+) -> Result<bool, String> {
+    let has_input = fs::read_to_string(input_path)
+        .map(|input| !input.is_empty())
+        .unwrap_or(false);
+
+    if has_input {
+        return Ok(false);
+    }
+
+    let input = website.download_input(year, day)?;
+    fs::write(input_path, input).map_err(|error| error.to_string())?;
+
+    Ok(true)
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+    match response {
+        Ok(response) => response.into_string().map_err(|error| error.to_string()),
+        Err(ureq::Error::Status(400, _)) => Err(String::from("invalid session token")),
+        Err(ureq::Error::Status(404, _)) => {
+            Err(String::from("puzzle not found, or not unlocked yet"))
+        }
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+/// URL of the Advent of Code website, which can be replaced using the `AOC_BASE_URL` env variable
+/// (e.g. to use a local server)
+fn read_base_url() -> String {
+    let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+
+    base_url.trim_end_matches('/').to_string()
+}
+
+/// Read the session token from the `AOC_SESSION` env variable, or else from the `.aoc_session` file
+fn read_session() -> Option<String> {
+    let session = env::var("AOC_SESSION").or_else(|_| fs::read_to_string(SESSION_FILE_PATH));
+
+    session.ok().map(|session| session.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serve a single request with the given body, returning the content of the request
+    fn serve_once(body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let length = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..length]);
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            String::from_utf8(request).unwrap()
+        });
+
+        (base_url, server)
+    }
+
+    fn create_input_path(name: &str) -> PathBuf {
+        let folder = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+        fs::create_dir_all(&folder).unwrap();
+
+        folder.join("input.txt")
+    }
+
+    #[test]
+    fn download_input_from_local_server() {
+        let (base_url, server) = serve_once("1000\n2000\n");
+        let website = Website {
+            base_url,
+            session: Some(String::from("test-session")),
+        };
+        let input_path = create_input_path("download");
+        let _ = fs::remove_file(&input_path);
+
+        let is_downloaded = download_missing_input(&website, &input_path, 2022, 1).unwrap();
+        let request = server.join().unwrap();
+
+        assert!(is_downloaded);
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=test-session"));
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1000\n2000\n");
+    }
+
+    #[test]
+    fn never_refetch_present_input() {
+        let input_path = create_input_path("present");
+        fs::write(&input_path, "already here\n").unwrap();
+
+        // nothing listens on this URL, and there is no session token, any request would fail
+        let website = Website {
+            base_url: String::from("http://127.0.0.1:1"),
+            session: None,
+        };
+        let is_downloaded = download_missing_input(&website, &input_path, 2022, 1).unwrap();
+
+        assert!(!is_downloaded);
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "already here\n");
+    }
+}
@@ -1,150 +1,284 @@
+use crate::{
+    change_plan::ChangePlan,
+    config, days,
+    fetch::Website,
+    puzzle_page,
+    template::{self, TemplateVariables},
+};
 use chrono::{Datelike, Utc};
 use dialoguer::theme::ColorfulTheme;
 use dialoguer::Input;
 use regex::Regex;
-use std::{
-    fs::{self, File},
-    io::{self, Write},
-    path::Path,
-    process,
-};
+use std::{fs, io, path::Path, process};
 
 /// This script is made to generate folder for each day of Advent of Code.
-/// Examples: day02, day03, day04, etc.
-pub fn run(day: Option<u8>) {
+/// Examples: y2022/day02, y2022/day03, y2023/day01, etc.
+/// Every change is planned first, so it can be previewed (dry run) or applied all at once.
+pub fn run(
+    year: Option<u16>,
+    day: Option<u8>,
+    template_name: Option<String>,
+    use_fetch: bool,
+    use_fetch_sample: bool,
+    page_path: Option<String>,
+    dry_run: bool,
+) {
+    let year = config::get_year(year);
     let day = match day {
-        Some(day) => day as u8,
-        None => ask_day_input(),
+        Some(day) => day,
+        None => ask_day_input(year),
+    };
+    let day_path = days::get_day_path(year, day);
+
+    check_folder_already_exists(&day_path);
+
+    let template_name = template_name.unwrap_or_else(|| config::get().defaults.template.clone());
+
+    let template_path = match template::find_template(&template_name) {
+        Some(template_path) => template_path,
+        None => {
+            println!(
+                "Template {} not found! Available templates: {}",
+                template_name,
+                template::get_template_names().join(", ")
+            );
+            process::exit(1);
+        }
+    };
+
+    let website = Website::from_env();
+    let page = read_puzzle_page(&website, year, day, use_fetch_sample, page_path);
+
+    let plan = match plan_generation(
+        &website,
+        year,
+        day,
+        &template_path,
+        use_fetch,
+        page.as_deref(),
+    ) {
+        Ok(plan) => plan,
+        Err(error) => {
+            println!("Cannot generate folder {}: {}", day_path.display(), error);
+            process::exit(1);
+        }
     };
 
-    check_folder_already_exists(day);
-    copy_template(day);
-    import_module_on_main(day);
-    use_crate_on_puzzle(day);
-    add_arm_on_match_puzzle(day);
-    update_config_file(day);
+    if dry_run {
+        plan.display_diff();
+        return;
+    }
 
-    println!("Folder src/day{:02} successfully generated!", day);
+    if let Err(error) = plan.apply() {
+        println!("Cannot generate folder {}: {}", day_path.display(), error);
+        process::exit(1);
+    }
+
+    println!("Folder {} successfully generated!", day_path.display());
 }
 
-fn ask_day_input() -> u8 {
-    let now = Utc::now();
-    let current_day = now.day();
+fn plan_generation(
+    website: &Website,
+    year: u16,
+    day: u8,
+    template_path: &Path,
+    use_fetch: bool,
+    page: Option<&str>,
+) -> Result<ChangePlan, String> {
+    let mut plan = ChangePlan::default();
+    let day_path = days::get_day_path(year, day);
 
-    let day = Input::<u32>::with_theme(&ColorfulTheme::default())
-        .with_prompt("Enter the day")
-        .default(current_day)
-        .interact()
-        .unwrap();
+    let variables = TemplateVariables {
+        year,
+        day,
+        title: page.and_then(puzzle_page::extract_title),
+        sample_answer: page.and_then(|page| {
+            puzzle_page::extract_example_answers(page)
+                .into_iter()
+                .next()
+        }),
+    };
+
+    copy_template(&mut plan, template_path, &variables)
+        .map_err(|error| format!("cannot copy template: {}", error))?;
+    update_config_file(&mut plan, year, day)?;
+
+    if use_fetch {
+        let input = website
+            .download_input(year, day)
+            .map_err(|error| format!("cannot fetch input: {}", error))?;
+
+        plan.write(day_path.join("input.txt"), input)
+            .map_err(|error| error.to_string())?;
+    }
+
+    if let Some(page) = page {
+        extract_sample(&mut plan, &day_path, page, &variables)
+            .map_err(|error| error.to_string())?;
+    }
 
-    day as u8
+    Ok(plan)
 }
 
-fn check_folder_already_exists(day: u8) {
-    let path = format!("src/day{:02}", day);
-    let path = Path::new(&path);
+/// Read the puzzle page from a saved file, or download it from the website
+fn read_puzzle_page(
+    website: &Website,
+    year: u16,
+    day: u8,
+    use_fetch_sample: bool,
+    page_path: Option<String>,
+) -> Option<String> {
+    let page = match page_path {
+        Some(page_path) => fs::read_to_string(config::resolve_user_path(&page_path))
+            .map_err(|error| error.to_string()),
+        None if use_fetch_sample => website.get_page(&format!("/{}/day/{}", year, day)),
+        None => return None,
+    };
 
-    if path.exists() {
-        println!("Folder src/day{:02} already exists!", day);
-        process::exit(1);
+    match page {
+        Ok(page) => Some(page),
+        Err(error) => {
+            println!("Cannot read the puzzle page of day {}: {}", day, error);
+            process::exit(1);
+        }
     }
 }
 
-fn copy_template(day: u8) {
-    let src = format!("template");
-    let dist = format!("src/day{:02}", day);
+/// Save the sample of the puzzle page in the `sample.txt` file.
+/// The answer of the example is proposed as the expected sample answer, through the template placeholder.
+fn extract_sample(
+    plan: &mut ChangePlan,
+    day_path: &Path,
+    page: &str,
+    variables: &TemplateVariables,
+) -> io::Result<()> {
+    match puzzle_page::extract_sample(page) {
+        Some(sample) => plan.write(day_path.join("sample.txt"), sample)?,
+        None => println!("No sample found in the puzzle page"),
+    }
+
+    if let Some(sample_answer) = &variables.sample_answer {
+        println!(
+            "Proposed sample answer of part 1: {} (please check it in {})",
+            sample_answer,
+            day_path.join("answers.toml").display()
+        );
+    }
 
-    copy_dir_all(src, dist).unwrap();
+    Ok(())
 }
 
-fn import_module_on_main(day: u8) {
-    let main_path = Path::new("src/main.rs");
-    let mut main_file = fs::OpenOptions::new()
-        .read(true)
-        .write(true)
-        .append(true)
-        .open(main_path)
-        .unwrap();
-
-    let main_content = fs::read_to_string(main_path).unwrap();
-    let has_eol = main_content.ends_with("\n");
-    let line_start = if has_eol { "" } else { "\n" };
-
-    let main_content = format!("{}mod day{:02};", line_start, day);
-    main_file.write_all(main_content.as_bytes()).unwrap();
+fn ask_day_input(year: u16) -> u8 {
+    Input::<u8>::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!("Enter the day of {}", year))
+        .default(get_default_day(year))
+        .interact()
+        .unwrap()
 }
 
-fn use_crate_on_puzzle(day: u8) {
-    let puzzle_path = Path::new("src/puzzle.rs");
-    let mut puzzle_file = fs::OpenOptions::new()
-        .read(true)
-        .write(true)
-        .append(true)
-        .open(puzzle_path)
-        .unwrap();
-
-    let puzzle_content = fs::read_to_string(puzzle_path).unwrap();
-    let has_eol = puzzle_content.ends_with("\n");
-    let line_start = if has_eol { "" } else { "\n" };
-
-    let puzzle_content = format!(
-        "{}use crate::day{:02}::run as day{:02};",
-        line_start, day, day
-    );
-    puzzle_file.write_all(puzzle_content.as_bytes()).unwrap();
+/// Today during the event of the given year, else the first day not generated yet
+fn get_default_day(year: u16) -> u8 {
+    let now = Utc::now();
+    let is_during_event = now.year() == year as i32 && now.month() == 12 && now.day() <= 25;
+
+    if is_during_event {
+        return now.day() as u8;
+    }
+
+    (1..=25)
+        .find(|day| !days::get_day_path(year, *day).exists())
+        .unwrap_or(1)
 }
 
-fn add_arm_on_match_puzzle(day: u8) {
-    let puzzle_path = Path::new("src/puzzle.rs");
-    let puzzle_content = fs::read_to_string(puzzle_path).unwrap();
+fn check_folder_already_exists(day_path: &Path) {
+    if day_path.exists() {
+        println!("Folder {} already exists!", day_path.display());
+        process::exit(1);
+    }
+}
 
-    let original_text = r#"_ => panic!("Invalid day number. Did you forget to generate this day using the script?"),"#;
-    let replaced_text = format!("{} => day{:02}(),\n\t\t{}", day, day, original_text);
-    let puzzle_content = str::replace(&puzzle_content, original_text, &replaced_text);
+fn copy_template(
+    plan: &mut ChangePlan,
+    template_path: &Path,
+    variables: &TemplateVariables,
+) -> io::Result<()> {
+    let dist = days::get_day_path(variables.year, variables.day);
 
-    let mut puzzle_file = File::create(puzzle_path).unwrap();
-    puzzle_file.write_all(puzzle_content.as_bytes()).unwrap();
+    copy_dir_all(plan, template_path, dist, variables)
 }
 
-fn update_config_file(day: u8) {
-    let config_path = Path::new(".cargo/config.toml");
-    let config_content = fs::read_to_string(config_path).unwrap();
-
-    // update day number
-    let regex = Regex::new(r#"DAY = "\d+""#).unwrap();
-    let original_text = regex.find(&config_content).unwrap().as_str();
-    let replaced_text = format!("DAY = \"{}\"", day);
-    let config_content = str::replace(&config_content, original_text, &replaced_text);
-
-    // update part number
-    let regex = Regex::new(r#"PART = "\d+""#).unwrap();
-    let original_text = regex.find(&config_content).unwrap().as_str();
-    let replaced_text = "PART = \"1\"";
-    let config_content = str::replace(&config_content, original_text, &replaced_text);
-
-    // update use sample
-    let regex = Regex::new(r#"USE_SAMPLE = "(?:true|false)""#).unwrap();
-    let original_text = regex.find(&config_content).unwrap().as_str();
-    let replaced_text = "USE_SAMPLE = \"true\"";
-    let config_content = str::replace(&config_content, original_text, &replaced_text);
-
-    let mut config_file = File::create(config_path).unwrap();
-    config_file.write_all(config_content.as_bytes()).unwrap();
+fn update_config_file(plan: &mut ChangePlan, year: u16, day: u8) -> Result<(), String> {
+    let config_path = config::get().paths.env_config.as_path();
+    let config_content = fs::read_to_string(config_path)
+        .map_err(|error| format!("cannot read {}: {}", config_path.display(), error))?;
+
+    let config_content = replace_config_value(
+        &config_content,
+        r#"YEAR = "\d+""#,
+        &format!("YEAR = \"{}\"", year),
+    )?;
+    let config_content = replace_config_value(
+        &config_content,
+        r#"DAY = "\d+""#,
+        &format!("DAY = \"{}\"", day),
+    )?;
+    let config_content = replace_config_value(&config_content, r#"PART = "\d+""#, "PART = \"1\"")?;
+    let config_content = replace_config_value(
+        &config_content,
+        r#"USE_SAMPLE = "(?:true|false)""#,
+        "USE_SAMPLE = \"true\"",
+    )?;
+
+    plan.modify(config_path, config_content)
+        .map_err(|error| format!("cannot read {}: {}", config_path.display(), error))
 }
 
-/// Copy an entire directory recursively.
-/// Inspired by Simon Buchan https://stackoverflow.com/a/65192210
-fn copy_dir_all(src: impl AsRef<Path>, dist: impl AsRef<Path>) -> io::Result<()> {
-    fs::create_dir_all(&dist)?;
+pub fn replace_config_value(
+    config_content: &str,
+    pattern: &str,
+    replaced_text: &str,
+) -> Result<String, String> {
+    let regex = Regex::new(pattern).unwrap();
 
+    match regex.find(config_content) {
+        Some(original_text) => Ok(str::replace(
+            config_content,
+            original_text.as_str(),
+            replaced_text,
+        )),
+        None => Err(format!(
+            "missing {} in {}",
+            pattern,
+            config::get().paths.env_config.display()
+        )),
+    }
+}
+
+/// Copy an entire directory recursively, replacing the placeholders of each file.
+/// Inspired by Simon Buchan https://stackoverflow.com/a/65192210
+fn copy_dir_all(
+    plan: &mut ChangePlan,
+    src: impl AsRef<Path>,
+    dist: impl AsRef<Path>,
+    variables: &TemplateVariables,
+) -> io::Result<()> {
     for entry in fs::read_dir(src)? {
         let entry = entry?;
         let ty = entry.file_type()?;
 
         if ty.is_dir() {
-            copy_dir_all(entry.path(), dist.as_ref().join(entry.file_name()))?;
+            copy_dir_all(
+                plan,
+                entry.path(),
+                dist.as_ref().join(entry.file_name()),
+                variables,
+            )?;
         } else {
-            fs::copy(entry.path(), dist.as_ref().join(entry.file_name()))?;
+            let content = fs::read_to_string(entry.path())?;
+            plan.create(
+                dist.as_ref().join(entry.file_name()),
+                variables.render(&content),
+            );
         }
     }
 
@@ -0,0 +1,402 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Integer type usable as a coordinate
+pub trait Integer:
+    Copy
+    + Debug
+    + Default
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Sum, or nothing if it would overflow the type
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Difference, or nothing if it would overflow the type
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+/// Integer type which can hold a negative offset, used for the directions
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($type:ty),*) => {
+        $(impl Integer for $type {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$type>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$type>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_signed_integer {
+    ($($type:ty),*) => {
+        $(impl SignedInteger for $type {})*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_signed_integer!(i8, i16, i32, i64, isize);
+
+/// Point of a plane, the y axis going down like the lines of an input, also used as a vector
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Integer> Point2<T> {
+    pub const ZERO: Point2<T> = Point2 {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    pub fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point2<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Point2<usize> {
+    /// Point moved by a signed offset, unless a coordinate would become negative
+    pub fn checked_add_signed(self, offset: Point2<isize>) -> Option<Point2<usize>> {
+        Some(Point2 {
+            x: self.x.checked_add_signed(offset.x)?,
+            y: self.y.checked_add_signed(offset.y)?,
+        })
+    }
+}
+
+impl<T: Integer> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Integer> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, factor: T) -> Point2<T> {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: SignedInteger> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Point2<T> {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Integer> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Point2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Integer> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Point2<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+/// Point of a space, also used as a vector
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Integer> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    /// Points sharing a face with this one, in the six directions
+    pub fn neighbours(self) -> [Point3<T>; 6] {
+        let Point3 { x, y, z } = self;
+        let one = T::ONE;
+
+        [
+            Point3::new(x + one, y, z),
+            Point3::new(x - one, y, z),
+            Point3::new(x, y + one, z),
+            Point3::new(x, y - one, z),
+            Point3::new(x, y, z + one),
+            Point3::new(x, y, z - one),
+        ]
+    }
+}
+
+impl<T: Integer> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Integer> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, factor: T) -> Point3<T> {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: SignedInteger> Neg for Point3<T> {
+    type Output = Point3<T>;
+
+    fn neg(self) -> Point3<T> {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Integer> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Point3<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Integer> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Point3<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+/// Direction along the axes of a plane, the y axis going down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from the top
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Direction after a quarter turn counterclockwise
+    pub fn turn_left(self) -> Direction4 {
+        match self {
+            Direction4::Up => Direction4::Left,
+            Direction4::Right => Direction4::Up,
+            Direction4::Down => Direction4::Right,
+            Direction4::Left => Direction4::Down,
+        }
+    }
+
+    /// Direction after a quarter turn clockwise
+    pub fn turn_right(self) -> Direction4 {
+        match self {
+            Direction4::Up => Direction4::Right,
+            Direction4::Right => Direction4::Down,
+            Direction4::Down => Direction4::Left,
+            Direction4::Left => Direction4::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction4 {
+        self.turn_right().turn_right()
+    }
+
+    /// Vector of a single step in this direction
+    pub fn offset<T: SignedInteger>(self) -> Point2<T> {
+        match self {
+            Direction4::Up => Point2::new(T::ZERO, -T::ONE),
+            Direction4::Right => Point2::new(T::ONE, T::ZERO),
+            Direction4::Down => Point2::new(T::ZERO, T::ONE),
+            Direction4::Left => Point2::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+/// Direction along the axes and the diagonals of a plane, the y axis going down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from the top
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Direction after an eighth of a turn counterclockwise
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Direction after an eighth of a turn clockwise
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Vector of a single step in this direction
+    pub fn offset<T: SignedInteger>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction8::Up => (T::ZERO, -T::ONE),
+            Direction8::UpRight => (T::ONE, -T::ONE),
+            Direction8::Right => (T::ONE, T::ZERO),
+            Direction8::DownRight => (T::ONE, T::ONE),
+            Direction8::Down => (T::ZERO, T::ONE),
+            Direction8::DownLeft => (-T::ONE, T::ONE),
+            Direction8::Left => (-T::ONE, T::ZERO),
+            Direction8::UpLeft => (-T::ONE, -T::ONE),
+        };
+
+        Point2::new(x, y)
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Direction8 {
+        match direction {
+            Direction4::Up => Direction8::Up,
+            Direction4::Right => Direction8::Right,
+            Direction4::Down => Direction8::Down,
+            Direction4::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_direction_offsets() {
+        let mut point = Point2::new(2, 3);
+
+        point += Direction4::Up.offset();
+        point += Direction8::DownLeft.offset() * 2;
+
+        assert_eq!(point, Point2::new(0, 4));
+    }
+
+    #[test]
+    fn compute_manhattan_distance() {
+        assert_eq!(Point2::new(-2, 3).manhattan_distance(Point2::new(1, -1)), 7);
+        assert_eq!(
+            Point2::<u32>::new(2, 3).manhattan_distance(Point2::new(5, 1)),
+            5
+        );
+    }
+
+    #[test]
+    fn move_in_space() {
+        let mut point = Point3::new(1, -2, 3);
+
+        point += Point3::from((1, 1, 1)) * 2;
+        point -= -Point3::new(0, 0, 1);
+
+        assert_eq!(point, Point3::new(3, 0, 6));
+    }
+
+    #[test]
+    fn turn_around() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.opposite(), Direction4::Right);
+
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+    }
+
+    #[test]
+    fn stay_positive_with_checked_offset() {
+        let origin = Point2::<usize>::ZERO;
+
+        assert_eq!(origin.checked_add_signed(Direction4::Left.offset()), None);
+        assert_eq!(
+            origin.checked_add_signed(Direction4::Down.offset()),
+            Some(Point2::new(0, 1))
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use crate::{
+    geometry::{Direction4, Direction8, Point2},
+    parse_error::{self, InputLine, ParseError},
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Position of a cell in a dense grid, starting from the top left corner
+pub type Position = Point2<usize>;
+
+/// Position of a cell in a sparse grid, which can be negative
+pub type SparsePosition = Point2<i64>;
+
+/// Rectangle of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells, given row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A grid of {}x{} must contain {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        match self.contains(position) {
+            true => Some(&self.cells[self.index_of(position)]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => {
+                let index = self.index_of(position);
+                Some(&mut self.cells[index])
+            }
+            false => None,
+        }
+    }
+
+    /// Position of the next cell in the given direction, if still inside the grid
+    pub fn neighbour(&self, position: Position, direction: Direction4) -> Option<Position> {
+        position
+            .checked_add_signed(direction.offset())
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Positions of the cells next to the given one, in the four directions
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbour(position, direction))
+    }
+
+    /// Positions of the cells next to the given one, in the eight directions
+    #[allow(dead_code)] // used by the days generated from the grid template
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL.into_iter().filter_map(move |direction| {
+            position
+                .checked_add_signed(direction.offset())
+                .filter(|neighbour| self.contains(*neighbour))
+        })
+    }
+
+    /// Positions of every cell, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Point2::new(index % width, index / width))
+    }
+
+    /// Cells along with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Cells of the row `y`, from left to right, if inside the grid
+    pub fn row(&self, y: usize) -> Option<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        if y >= self.height {
+            return None;
+        }
+
+        Some(self.cells[y * self.width..(y + 1) * self.width].iter())
+    }
+
+    /// Cells of the column `x`, from top to bottom, if inside the grid
+    pub fn column(
+        &self,
+        x: usize,
+    ) -> Option<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        if x >= self.width {
+            return None;
+        }
+
+        Some(self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Create a grid of the same size, applying a function to each cell
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn index_of(&self, position: Position) -> usize {
+        position.y * self.width + position.x
+    }
+}
+
+impl<T: Default> Grid<T> {
+    /// Parse each character of the input as a cell, shorter lines being padded with the default value
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse_lines(parse_error::lines(input), expected, parse_cell)
+    }
+
+    /// Parse each character of the lines as a cell, when the grid is only a part of the input
+    pub fn parse_lines<'a>(
+        lines: impl IntoIterator<Item = InputLine<'a>>,
+        expected: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows = Vec::new();
+
+        for line in lines {
+            let row = line
+                .text
+                .chars()
+                .enumerate()
+                .map(|(x, char)| parse_cell(char).ok_or_else(|| line.error_at(x, expected)))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            rows.push(row);
+        }
+
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let height = rows.len();
+
+        let mut cells = Vec::with_capacity(width * height);
+
+        for row in rows {
+            let padding = width - row.len();
+
+            cells.extend(row);
+            cells.extend((0..padding).map(|_| T::default()));
+        }
+
+        Ok(Grid::new(width, height, cells))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Position {:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position {:?} is outside of the grid", position))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Cells scattered on an unbounded plane, for maps which grow or mostly contain nothing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<SparsePosition, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn contains(&self, position: SparsePosition) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn get(&self, position: SparsePosition) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    /// Set the cell at the given position, returning the previous one
+    pub fn insert(&mut self, position: SparsePosition, value: T) -> Option<T> {
+        self.cells.insert(position, value)
+    }
+
+    pub fn remove(&mut self, position: SparsePosition) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    /// Positions of the cells, in no particular order
+    pub fn positions(&self) -> impl Iterator<Item = SparsePosition> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Cells along with their position, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (SparsePosition, &T)> {
+        self.cells
+            .iter()
+            .map(|(position, value)| (*position, value))
+    }
+
+    /// Top left and bottom right corners of the smallest rectangle containing every cell
+    pub fn bounds(&self) -> Option<(SparsePosition, SparsePosition)> {
+        let mut positions = self.positions();
+        let first = positions.next()?;
+
+        let bounds = positions.fold((first, first), |(min, max), position| {
+            (
+                Point2::new(min.x.min(position.x), min.y.min(position.y)),
+                Point2::new(max.x.max(position.x), max.y.max(position.y)),
+            )
+        });
+
+        Some(bounds)
+    }
+
+    /// Positions next to the given one, including the diagonals, filled or not
+    pub fn neighbours8(&self, position: SparsePosition) -> impl Iterator<Item = SparsePosition> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| position + direction.offset())
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(SparsePosition, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (SparsePosition, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    /// Display the bounding rectangle of the cells, the empty ones as `.`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Point2::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "abc\nde\nfgh";
+
+    fn parse_sample() -> Grid<char> {
+        Grid::parse(SAMPLE, "expected a letter", |char| {
+            char.is_ascii_alphabetic().then_some(char)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_and_pad_shorter_lines() {
+        let grid = parse_sample();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[Point2::new(1, 1)], 'e');
+        assert_eq!(grid[Point2::new(2, 1)], char::default());
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+    }
+
+    #[test]
+    fn locate_invalid_cell() {
+        let error = Grid::parse("ab\na#", "expected a letter", |char| {
+            char.is_ascii_alphabetic().then_some(char)
+        })
+        .unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn iterate_rows_and_columns() {
+        let grid = parse_sample();
+
+        assert_eq!(grid.row(2).unwrap().collect::<String>(), "fgh");
+        assert_eq!(grid.column(0).unwrap().rev().collect::<String>(), "fda");
+        assert!(grid.row(3).is_none());
+        assert!(grid.column(3).is_none());
+    }
+
+    #[test]
+    fn stay_inside_grid_for_neighbours() {
+        let grid = parse_sample();
+
+        assert_eq!(
+            grid.neighbours(Point2::ZERO).collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8(Point2::ZERO).collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(1, 1), Point2::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point2::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn display_grid() {
+        let grid = Grid::new(2, 2, vec!['#', '.', '.', '#']);
+
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+    }
+
+    #[test]
+    fn display_bounds_of_sparse_grid() {
+        let grid = [(Point2::new(-1, 0), '#'), (Point2::new(1, 1), '#')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+
+        assert_eq!(grid.bounds(), Some((Point2::new(-1, 0), Point2::new(1, 1))));
+        assert_eq!(
+            grid.neighbours8(Point2::ZERO)
+                .filter(|position| grid.contains(*position))
+                .count(),
+            2
+        );
+        assert_eq!(grid.to_string(), "#..\n..#\n");
+    }
+}
@@ -0,0 +1,210 @@
+use chrono::Utc;
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
+const HISTORY_PATH: &str = "history.csv";
+const HISTORY_HEADER: &str = "timestamp,commit,year,day,part,input,measurement,duration_ms";
+
+/// How a duration was measured, only the durations measured the same way can be compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Measurement {
+    /// Single run of the whole part, by the `run` and `run-all` commands
+    Run,
+    /// Median of the parse and solve durations of the measured runs, by the `bench` command
+    Bench,
+}
+
+impl Measurement {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Measurement::Run => "run",
+            Measurement::Bench => "bench",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Measurement> {
+        match value {
+            "run" => Some(Measurement::Run),
+            "bench" => Some(Measurement::Bench),
+            _ => None,
+        }
+    }
+}
+
+/// Duration of a puzzle run, saved as a line of the history file
+#[derive(Debug, Clone)]
+pub struct HistoryRecord {
+    pub timestamp: String,
+    pub commit: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub measurement: Measurement,
+    pub duration_ms: f64,
+}
+
+impl HistoryRecord {
+    /// Create a record of a run made now, on the current git commit
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        input: &str,
+        measurement: Measurement,
+        duration_ms: f64,
+    ) -> HistoryRecord {
+        HistoryRecord {
+            timestamp: Utc::now().to_rfc3339(),
+            commit: read_commit_hash(),
+            year,
+            day,
+            part,
+            input: input.to_string(),
+            measurement,
+            duration_ms,
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{:.3}",
+            self.timestamp,
+            self.commit,
+            self.year,
+            self.day,
+            self.part,
+            self.input,
+            self.measurement.as_str(),
+            self.duration_ms
+        )
+    }
+
+    fn from_line(line: &str) -> Option<HistoryRecord> {
+        let values = line.split(',').collect::<Vec<_>>();
+
+        match values[..] {
+            [timestamp, commit, year, day, part, input, measurement, duration_ms] => {
+                Some(HistoryRecord {
+                    timestamp: timestamp.to_string(),
+                    commit: commit.to_string(),
+                    year: year.parse().ok()?,
+                    day: day.parse().ok()?,
+                    part: part.parse().ok()?,
+                    input: input.to_string(),
+                    measurement: Measurement::parse(measurement)?,
+                    duration_ms: duration_ms.parse().ok()?,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Append records at the end of the history file, creating it if needed
+pub fn append(records: &[HistoryRecord]) {
+    let path = Path::new(HISTORY_PATH);
+    let is_new_file = !path.exists();
+
+    let mut history_file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .unwrap();
+
+    if is_new_file {
+        writeln!(history_file, "{}", HISTORY_HEADER).unwrap();
+    }
+
+    for record in records {
+        writeln!(history_file, "{}", record.to_line()).unwrap();
+    }
+}
+
+/// Read all records of the history file, from the oldest to the most recent
+pub fn read() -> Vec<HistoryRecord> {
+    let content = fs::read_to_string(HISTORY_PATH).unwrap_or_default();
+
+    content
+        .lines()
+        .skip(1)
+        .filter_map(HistoryRecord::from_line)
+        .collect()
+}
+
+/// Read the hash of the current commit, by following the reference of the `.git/HEAD` file
+fn read_commit_hash() -> String {
+    let head = match fs::read_to_string(".git/HEAD") {
+        Ok(head) => head.trim().to_string(),
+        Err(_) => return String::from("unknown"),
+    };
+
+    let reference = match head.strip_prefix("ref: ") {
+        Some(reference) => reference,
+        None => return head,
+    };
+
+    if let Ok(hash) = fs::read_to_string(Path::new(".git").join(reference)) {
+        return hash.trim().to_string();
+    }
+
+    // the reference may only be stored in the packed refs file
+    let packed_refs = fs::read_to_string(".git/packed-refs").unwrap_or_default();
+
+    packed_refs
+        .lines()
+        .find_map(|line| match line.split_once(' ') {
+            Some((hash, name)) if name == reference => Some(hash.to_string()),
+            _ => None,
+        })
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_record_line() {
+        let record = HistoryRecord::from_line(
+            "2022-12-01T10:00:00+00:00,9bda2ed,2022,7,1,sample,bench,0.125",
+        )
+        .unwrap();
+
+        assert_eq!(record.timestamp, "2022-12-01T10:00:00+00:00");
+        assert_eq!(record.commit, "9bda2ed");
+        assert_eq!(record.year, 2022);
+        assert_eq!(record.day, 7);
+        assert_eq!(record.part, 1);
+        assert_eq!(record.input, "sample");
+        assert_eq!(record.measurement, Measurement::Bench);
+        assert_eq!(record.duration_ms, 0.125);
+    }
+
+    #[test]
+    fn write_and_read_back_record() {
+        let record = HistoryRecord::new(2022, 16, 2, "input", Measurement::Run, 1234.5678);
+        let read_record = HistoryRecord::from_line(&record.to_line()).unwrap();
+
+        assert_eq!(read_record.day, 16);
+        assert_eq!(read_record.measurement, Measurement::Run);
+        assert_eq!(read_record.duration_ms, 1234.568);
+    }
+
+    #[test]
+    fn skip_invalid_lines() {
+        assert!(HistoryRecord::from_line(HISTORY_HEADER).is_none());
+        assert!(HistoryRecord::from_line("2022-12-01T10:00:00+00:00,9bda2ed,2022,7").is_none());
+        assert!(HistoryRecord::from_line(
+            "2022-12-01T10:00:00+00:00,9bda2ed,2022,x,1,input,run,1.0"
+        )
+        .is_none());
+        assert!(HistoryRecord::from_line(
+            "2022-12-01T10:00:00+00:00,9bda2ed,2022,7,1,input,other,1.0"
+        )
+        .is_none());
+    }
+}
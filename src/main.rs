@@ -1,41 +1,76 @@
+mod answers;
+mod bench;
+mod change_plan;
 mod cli;
+mod config;
+mod cycle;
+mod days;
+mod fetch;
 mod generate;
+mod geometry;
+mod grid;
+mod history;
+mod parse_error;
+mod pathfinding;
 mod puzzle;
+mod puzzle_page;
+mod range_set;
+mod remove;
+mod solution;
+mod submit;
+mod template;
 
 use clap::Parser;
-use cli::{Args, Commands};
+use cli::{Args, BenchCommands, Commands, Format};
 
 fn main() {
     let args = Args::parse();
+    config::load();
+
+    let year = args.year;
 
     match args.command {
-        Some(Commands::Generate { day }) => generate::run(day),
-        None => puzzle::run(),
+        Some(Commands::Run {
+            day,
+            part,
+            sample,
+            input,
+            format,
+            history,
+        }) => puzzle::run(year, day, part, sample, input, format, history),
+        Some(Commands::Bench {
+            command: Some(BenchCommands::Compare { threshold }),
+            ..
+        }) => bench::compare(threshold),
+        Some(Commands::Bench {
+            command: None,
+            day,
+            part,
+            sample,
+            runs,
+            warmup,
+            history,
+        }) => bench::run(year, day, part, sample, runs, warmup, history),
+        Some(Commands::RunAll {
+            inputs,
+            format,
+            history,
+        }) => puzzle::run_all(year, inputs, format, history),
+        Some(Commands::Generate {
+            day,
+            template,
+            fetch,
+            fetch_sample,
+            page,
+            dry_run,
+        }) => generate::run(year, day, template, fetch, fetch_sample, page, dry_run),
+        Some(Commands::Remove {
+            day,
+            force,
+            dry_run,
+        }) => remove::run(year, day, force, dry_run),
+        Some(Commands::Submit { day, part }) => submit::run(year, day, part),
+        Some(Commands::Fetch { day }) => fetch::run(year, day),
+        None => puzzle::run(year, None, None, None, None, Format::Text, false),
     }
 }
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
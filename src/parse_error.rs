@@ -0,0 +1,172 @@
+use regex::{Captures, Regex};
+use std::{any::type_name, fmt, str::FromStr};
+
+/// Error of an input parser, located at the offending character of the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Day of the puzzle, set by the runner
+    pub day: Option<u8>,
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number, starting at 1
+    pub column: usize,
+    /// Content of the offending line
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn with_day(self, day: u8) -> ParseError {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// Display the error followed by the offending line, with a caret under the bad character
+    pub fn render(&self) -> String {
+        let line_number = self.line.to_string();
+        let margin = " ".repeat(line_number.len());
+
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}^",
+            self,
+            margin,
+            line_number,
+            self.text,
+            margin,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "Cannot parse the input of day {}", day)?,
+            None => write!(f, "Cannot parse the input")?,
+        }
+
+        write!(
+            f,
+            " at line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/// Line of an input, used to locate the parse errors
+#[derive(Debug, Clone, Copy)]
+pub struct InputLine<'a> {
+    /// Index of the line, starting at 0
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn new(index: usize, text: &'a str) -> InputLine<'a> {
+        InputLine { index, text }
+    }
+
+    /// Error located at the given character of the line, starting at 0
+    pub fn error_at(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: None,
+            line: self.index + 1,
+            column: column + 1,
+            text: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error located at a token of the line, which must be a slice of the line text
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let token_start = token.as_ptr() as usize;
+
+        let column = if token_start >= start && token_start <= start + self.text.len() {
+            self.text[..token_start - start].chars().count()
+        } else {
+            0
+        };
+
+        self.error_at(column, message)
+    }
+
+    /// Error located at the end of the line, when an expected value is missing
+    pub fn missing(&self, message: impl Into<String>) -> ParseError {
+        self.error_at(self.text.chars().count(), message)
+    }
+
+    /// Parse a token of the line, which must be a slice of the line text
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse::<T>().map_err(|_| {
+            self.error(
+                token,
+                format!("expected {}, found {:?}", type_name::<T>(), token),
+            )
+        })
+    }
+
+    /// Match the whole line against a regex
+    pub fn captures(&self, regex: &Regex) -> Result<Captures<'a>, ParseError> {
+        regex
+            .captures(self.text)
+            .ok_or_else(|| self.error_at(0, format!("expected a line like {}", regex.as_str())))
+    }
+}
+
+/// Lines of the input, along with their index
+pub fn lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| InputLine::new(index, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_token_in_line() {
+        let line = InputLine::new(11, "move x from 1 to 3");
+        let token = line.text.split(' ').nth(1).unwrap();
+
+        let error = line.parse::<u8>(token).unwrap_err();
+
+        assert_eq!(error.line, 12);
+        assert_eq!(error.column, 6);
+        assert_eq!(error.message, "expected u8, found \"x\"");
+    }
+
+    #[test]
+    fn locate_missing_value_at_end_of_line() {
+        let line = InputLine::new(0, "2-4");
+
+        assert_eq!(line.missing("expected a comma").column, 4);
+    }
+
+    #[test]
+    fn render_caret_under_bad_character() {
+        let error = InputLine::new(11, "move x from 1 to 3")
+            .error_at(5, "expected u8")
+            .with_day(5);
+
+        assert_eq!(
+            error.render(),
+            "Cannot parse the input of day 5 at line 12, column 6: expected u8\n   |\n12 | move x from 1 to 3\n   |      ^"
+        );
+    }
+
+    #[test]
+    fn report_unmatched_regex() {
+        let regex = Regex::new(r"^(\d+)$").unwrap();
+        let line = InputLine::new(0, "abc");
+
+        let error = line.captures(&regex).unwrap_err();
+
+        assert_eq!(error.column, 1);
+        assert_eq!(error.message, r"expected a line like ^(\d+)$");
+    }
+}
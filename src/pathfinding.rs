@@ -0,0 +1,274 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+pub type Cost = u32;
+
+/// Nodes linked to their neighbours, searched for the shortest paths between them
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// Nodes reachable in a single move from the given one
+    fn neighbours(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+    /// Cost of the move between two neighbours, ignored by the breadth-first search
+    fn cost(&self, _from: &Self::Node, _to: &Self::Node) -> Cost {
+        1
+    }
+}
+
+/// Adjacency lists, each node being mapped to its neighbours
+impl<N: Clone + Eq + Hash> Graph for HashMap<N, Vec<N>> {
+    type Node = N;
+
+    fn neighbours(&self, node: &N) -> Vec<N> {
+        self.get(node).cloned().unwrap_or_default()
+    }
+}
+
+/// Shortest distances found by a search, from the closest of its starting nodes
+#[derive(Debug)]
+pub struct Search<N> {
+    distances: HashMap<N, Cost>,
+    parents: HashMap<N, N>,
+    target: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Search<N> {
+        Search {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            target: None,
+        }
+    }
+
+    /// Distance to the first target reached, if any
+    pub fn target_distance(&self) -> Option<Cost> {
+        self.target.as_ref().map(|target| self.distances[target])
+    }
+
+    /// Nodes reached by the search along with their distance
+    pub fn distances(&self) -> impl Iterator<Item = (&N, Cost)> {
+        self.distances
+            .iter()
+            .map(|(node, distance)| (node, *distance))
+    }
+
+    /// Shortest path from a starting node to the given one, both included
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Breadth-first search, each move costing 1, stopping at the first target reached
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_target: impl Fn(&G::Node) -> bool,
+) -> Search<G::Node> {
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_target(&node) {
+            search.target = Some(node);
+            break;
+        }
+
+        let distance = search.distances[&node] + 1;
+
+        for neighbour in graph.neighbours(&node) {
+            if search.distances.contains_key(&neighbour) {
+                continue;
+            }
+
+            search.distances.insert(neighbour.clone(), distance);
+            search.parents.insert(neighbour.clone(), node.clone());
+            queue.push_back(neighbour);
+        }
+    }
+
+    search
+}
+
+/// A* search, guided by a heuristic which must never overestimate the distance to a target.
+/// Using the cost of the moves, it is a Dijkstra search when the heuristic is always zero.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_target: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> Cost,
+) -> Search<G::Node> {
+    let mut search = Search::new();
+    let mut open_set = BinaryHeap::new();
+
+    for start in starts {
+        search.distances.insert(start.clone(), 0);
+        open_set.push(Candidate {
+            estimate: heuristic(&start),
+            distance: 0,
+            node: start,
+        });
+    }
+
+    while let Some(Candidate { distance, node, .. }) = open_set.pop() {
+        // a shorter path to this node has been found since it was added
+        if distance > search.distances[&node] {
+            continue;
+        }
+
+        if is_target(&node) {
+            search.target = Some(node);
+            break;
+        }
+
+        for neighbour in graph.neighbours(&node) {
+            let neighbour_distance = distance + graph.cost(&node, &neighbour);
+
+            let is_shorter = match search.distances.get(&neighbour) {
+                Some(known_distance) => neighbour_distance < *known_distance,
+                None => true,
+            };
+            if !is_shorter {
+                continue;
+            }
+
+            search
+                .distances
+                .insert(neighbour.clone(), neighbour_distance);
+            search.parents.insert(neighbour.clone(), node.clone());
+
+            open_set.push(Candidate {
+                estimate: neighbour_distance + heuristic(&neighbour),
+                distance: neighbour_distance,
+                node: neighbour,
+            });
+        }
+    }
+
+    search
+}
+
+/// Node waiting in the open set, the lowest estimate being popped first
+struct Candidate<N> {
+    estimate: Cost,
+    distance: Cost,
+    node: N,
+}
+
+impl<N> PartialEq for Candidate<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Candidate<N> {}
+
+impl<N> PartialOrd for Candidate<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Candidate<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_graph(edges: &[(char, char)]) -> HashMap<char, Vec<char>> {
+        let mut graph = HashMap::<char, Vec<char>>::new();
+
+        for (from, to) in edges {
+            graph.entry(*from).or_default().push(*to);
+            graph.entry(*to).or_default().push(*from);
+        }
+
+        graph
+    }
+
+    /// Moves along the alphabet, skipping a letter costing 1 more
+    struct Alphabet;
+
+    impl Graph for Alphabet {
+        type Node = u8;
+
+        fn neighbours(&self, node: &u8) -> Vec<u8> {
+            [node + 1, node + 2]
+                .into_iter()
+                .filter(|neighbour| *neighbour <= b'z')
+                .collect()
+        }
+
+        fn cost(&self, from: &u8, to: &u8) -> Cost {
+            ((to - from) * 2 - 1) as Cost
+        }
+    }
+
+    #[test]
+    fn find_shortest_path_with_bfs() {
+        let graph = create_graph(&[('a', 'b'), ('b', 'c'), ('c', 'd'), ('a', 'e'), ('e', 'd')]);
+
+        let search = bfs(&graph, ['a'], |node| *node == 'd');
+
+        assert_eq!(search.target_distance(), Some(2));
+        assert_eq!(search.path(&'d'), Some(vec!['a', 'e', 'd']));
+        assert_eq!(search.path(&'z'), None);
+    }
+
+    #[test]
+    fn start_from_the_closest_source() {
+        let graph = create_graph(&[('a', 'b'), ('b', 'c'), ('c', 'd'), ('d', 'e')]);
+
+        let search = bfs(&graph, ['a', 'd'], |_| false);
+
+        assert_eq!(search.target_distance(), None);
+        assert_eq!(
+            search.distances().map(|(_, distance)| distance).max(),
+            Some(1)
+        );
+        assert_eq!(search.path(&'b'), Some(vec!['a', 'b']));
+    }
+
+    #[test]
+    fn use_cost_of_moves() {
+        let is_target = |node: &u8| *node == b'e';
+
+        let search = astar(&Alphabet, [b'a'], is_target, |_| 0);
+        assert_eq!(search.target_distance(), Some(4));
+        assert_eq!(search.path(&b'e').unwrap().len(), 5);
+
+        let search = astar(&Alphabet, [b'a'], is_target, |node| {
+            b'e'.saturating_sub(*node) as Cost
+        });
+        assert_eq!(search.target_distance(), Some(4));
+
+        let search = bfs(&Alphabet, [b'a'], is_target);
+        assert_eq!(search.target_distance(), Some(2));
+    }
+}
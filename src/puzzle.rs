@@ -1,42 +1,146 @@
-use chrono::Utc;
+use crate::{
+    answers::Verdict,
+    cli::{Format, Inputs},
+    config,
+    days::{self, Puzzle},
+    history::{self, HistoryRecord, Measurement},
+    parse_error::ParseError,
+};
+use chrono::{Duration, Utc};
 use colored::Colorize;
+use serde::Serialize;
+use std::{
+    any::Any,
+    env, fs,
+    io::{self, Read},
+    panic, process, slice,
+    str::FromStr,
+};
 
-pub fn run() {
-    let before = Utc::now();
+#[derive(Debug)]
+enum RunStatus {
+    Correct,
+    Wrong(String),
+    Unverified,
+    Failed(String),
+}
+
+#[derive(Debug)]
+struct PuzzleRun {
+    year: u16,
+    day: u8,
+    part: u8,
+    input_name: String,
+    duration: Duration,
+    answer: Option<String>,
+    status: RunStatus,
+}
+
+/// Input given by the user, instead of the files embedded in the day module
+struct CustomInput {
+    name: String,
+    content: String,
+}
+
+/// Result of a puzzle run, serialized as a single line of JSON
+#[derive(Serialize)]
+struct PuzzleRunReport<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &'a str,
+    answer: Option<&'a str>,
+    duration_ms: f64,
+    status: &'static str,
+    expected_answer: Option<&'a str>,
+    error: Option<&'a str>,
+}
+
+pub fn run(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    use_sample: Option<bool>,
+    input_path: Option<String>,
+    format: Format,
+    use_history: bool,
+) {
+    let year = config::get_year(year);
+    let day = day.unwrap_or_else(|| read_env_variable("DAY"));
+    let part = part.unwrap_or_else(|| read_env_variable("PART"));
 
-    let day = env!("DAY").parse::<u8>().unwrap();
-
-    match day {
-        1 => day01(),
-        2 => day02(),
-        3 => day03(),
-        4 => day04(),
-        5 => day05(),
-        6 => day06(),
-        7 => day07(),
-        8 => day08(),
-        9 => day09(),
-        10 => day10(),
-        11 => day11(),
-        12 => day12(),
-        13 => day13(),
-        14 => day14(),
-        15 => day15(),
-        16 => day16(),
-        17 => day17(),
-        18 => day18(),
-        19 => day19(),
-        20 => day20(),
-        21 => day21(),
-        22 => day22(),
-        23 => day23(),
-        24 => day24(),
-        25 => day25(),
-        _ => panic!("Invalid day number. Did you forget to generate this day using the script?"),
+    let puzzle = get_puzzle(year, day);
+    check_part(puzzle, part);
+
+    let input = input_path.as_deref().map(read_input_file);
+    let use_sample = match input {
+        Some(_) => false,
+        None => read_use_sample(use_sample),
+    };
+
+    if format == Format::Json {
+        let custom_input =
+            input_path
+                .as_deref()
+                .zip(input)
+                .map(|(input_path, input)| CustomInput {
+                    name: get_input_name(input_path).to_string(),
+                    content: input,
+                });
+
+        let puzzle_run = try_run_puzzle(puzzle, part, use_sample, custom_input.as_ref());
+        display_json(&puzzle_run);
+
+        if use_history && custom_input.is_none() {
+            record_history(slice::from_ref(&puzzle_run));
+        }
+
+        if has_failed(&puzzle_run) {
+            process::exit(1);
+        }
+        return;
     }
 
+    display_info(year, day, part, use_sample);
+
+    if let Some(input_path) = input_path.as_deref() {
+        println!(r"/!\ Input from {} /!\", get_input_name(input_path));
+    }
+
+    let before = Utc::now();
+
+    let result = match &input {
+        Some(input) => solve_puzzle(puzzle, part, input),
+        None => run_puzzle(puzzle, part, use_sample),
+    };
+
     let after = Utc::now();
 
+    let answer = match result {
+        Ok(answer) => answer,
+        Err(error) => {
+            println!("{}", error.render().red());
+            process::exit(1);
+        }
+    };
+
+    println!("{}", answer);
+
+    // answers of a custom input are unknown
+    let verdict = match input {
+        Some(_) => Verdict::Unknown,
+        None => puzzle.get_answers().verify(part, use_sample, &answer),
+    };
+
+    match &verdict {
+        Verdict::Correct => println!("{}", "✓ Correct answer".green()),
+        Verdict::Wrong(expected_answer) => println!(
+            "{}",
+            format!("✗ Wrong answer, expected {}", expected_answer).red()
+        ),
+        Verdict::Unknown => {}
+    }
+
     let duration = after - before;
     let duration_text = match duration.num_seconds() {
         seconds if seconds > 3 => format!("Took {} seconds. Really slow...", seconds).italic(),
@@ -45,30 +149,378 @@ pub fn run() {
     };
 
     println!("{}", duration_text);
+
+    // durations of a custom input cannot be compared
+    if use_history && input.is_none() {
+        let input_name = get_embedded_input_name(use_sample);
+        let record = HistoryRecord::new(
+            year,
+            day,
+            part,
+            input_name,
+            Measurement::Run,
+            get_duration_ms(duration),
+        );
+
+        history::append(&[record]);
+    }
+
+    if let Verdict::Wrong(_) = verdict {
+        process::exit(1);
+    }
+}
+
+/// Run every part of every day of the given year (or all years), then display a summary of all runs.
+/// Exit with an error code if any of the puzzles failed or gave a wrong answer.
+pub fn run_all(year: Option<u16>, inputs: Inputs, format: Format, use_history: bool) {
+    let sample_options = match inputs {
+        Inputs::Sample => vec![true],
+        Inputs::Real => vec![false],
+        Inputs::Both => vec![true, false],
+    };
+
+    let mut puzzle_runs = Vec::new();
+
+    let puzzles = days::PUZZLES
+        .iter()
+        .filter(|puzzle| year.is_none_or(|year| puzzle.year == year));
+
+    for puzzle in puzzles {
+        for part in 1..=puzzle.parts {
+            for use_sample in sample_options.iter() {
+                if format == Format::Text {
+                    display_info(puzzle.year, puzzle.day, part, *use_sample);
+                }
+
+                let puzzle_run = try_run_puzzle(puzzle, part, *use_sample, None);
+
+                if format == Format::Json {
+                    display_json(&puzzle_run);
+                }
+
+                puzzle_runs.push(puzzle_run);
+            }
+        }
+    }
+
+    if format == Format::Text {
+        display_summary(&puzzle_runs);
+    }
+
+    if use_history {
+        record_history(&puzzle_runs);
+    }
+
+    if puzzle_runs.iter().any(has_failed) {
+        process::exit(1);
+    }
+}
+
+fn run_puzzle(puzzle: &Puzzle, part: u8, use_sample: bool) -> Result<String, ParseError> {
+    (puzzle.solve)(part, puzzle.get_input(use_sample), use_sample)
+        .map_err(|error| error.with_day(puzzle.day))
+}
+
+/// Solve a puzzle using the given input, instead of the files embedded in the day module
+fn solve_puzzle(puzzle: &Puzzle, part: u8, input: &str) -> Result<String, ParseError> {
+    (puzzle.solve)(part, input, false).map_err(|error| error.with_day(puzzle.day))
+}
+
+/// Run a puzzle, catching any parse error or panic (failed assertion, invalid part, etc...)
+/// The answer of a custom input cannot be verified.
+fn try_run_puzzle(
+    puzzle: &Puzzle,
+    part: u8,
+    use_sample: bool,
+    custom_input: Option<&CustomInput>,
+) -> PuzzleRun {
+    let before = Utc::now();
+
+    let result = panic::catch_unwind(|| match custom_input {
+        Some(custom_input) => solve_puzzle(puzzle, part, &custom_input.content),
+        None => run_puzzle(puzzle, part, use_sample),
+    });
+
+    let after = Utc::now();
+
+    let (answer, status) = match result {
+        Ok(Ok(answer)) => {
+            let verdict = match custom_input {
+                Some(_) => Verdict::Unknown,
+                None => puzzle.get_answers().verify(part, use_sample, &answer),
+            };
+
+            let status = match verdict {
+                Verdict::Correct => RunStatus::Correct,
+                Verdict::Wrong(expected_answer) => RunStatus::Wrong(expected_answer),
+                Verdict::Unknown => RunStatus::Unverified,
+            };
+
+            (Some(answer), status)
+        }
+        Ok(Err(error)) => (None, RunStatus::Failed(error.to_string())),
+        Err(payload) => (None, RunStatus::Failed(get_panic_message(payload))),
+    };
+
+    let input_name = match custom_input {
+        Some(custom_input) => custom_input.name.clone(),
+        None => get_embedded_input_name(use_sample).to_string(),
+    };
+
+    PuzzleRun {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        input_name,
+        duration: after - before,
+        answer,
+        status,
+    }
+}
+
+fn get_panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    String::from("unknown error")
+}
+
+fn display_summary(puzzle_runs: &[PuzzleRun]) {
+    println!();
+    println!("{}", "====  Summary  ====".bold());
+    println!();
+    println!(
+        "{:>4} | {:>4} | {:>4} | {:<6} | {:>10} | {:<20} | Status",
+        "Year", "Day", "Part", "Input", "Duration", "Answer"
+    );
+    println!("{}", "-".repeat(78));
+
+    for puzzle_run in puzzle_runs {
+        let duration = format!("{}ms", puzzle_run.duration.num_milliseconds());
+        let duration = match puzzle_run.duration.num_seconds() {
+            seconds if seconds > 3 => format!("{:>10}", duration).red(),
+            seconds if seconds > 0 => format!("{:>10}", duration).yellow(),
+            _ => format!("{:>10}", duration).normal(),
+        };
+
+        let answer = match &puzzle_run.answer {
+            Some(answer) => get_single_line(answer),
+            None => String::new(),
+        };
+
+        let status = match &puzzle_run.status {
+            RunStatus::Correct => "✓ correct".green(),
+            RunStatus::Wrong(expected_answer) => {
+                format!("✗ wrong, expected {}", get_single_line(expected_answer)).red()
+            }
+            RunStatus::Unverified => "? unverified".yellow(),
+            RunStatus::Failed(message) => format!("✗ failed: {}", message).red(),
+        };
+
+        println!(
+            "{:>4} | {:>4} | {:>4} | {:<6} | {} | {:<20} | {}",
+            puzzle_run.year,
+            puzzle_run.day,
+            puzzle_run.part,
+            puzzle_run.input_name,
+            duration,
+            answer,
+            status
+        );
+    }
+
+    let count_status = |predicate: fn(&RunStatus) -> bool| {
+        puzzle_runs
+            .iter()
+            .filter(|puzzle_run| predicate(&puzzle_run.status))
+            .count()
+    };
+
+    let total_correct = count_status(|status| matches!(status, RunStatus::Correct));
+    let total_wrong = count_status(|status| matches!(status, RunStatus::Wrong(_)));
+    let total_unverified = count_status(|status| matches!(status, RunStatus::Unverified));
+    let total_failed = count_status(|status| matches!(status, RunStatus::Failed(_)));
+
+    println!();
+    println!(
+        "{} correct, {} wrong, {} unverified, {} failed",
+        total_correct.to_string().green(),
+        total_wrong.to_string().red(),
+        total_unverified.to_string().yellow(),
+        total_failed.to_string().red()
+    );
+}
+
+fn has_failed(puzzle_run: &PuzzleRun) -> bool {
+    matches!(
+        puzzle_run.status,
+        RunStatus::Wrong(_) | RunStatus::Failed(_)
+    )
+}
+
+fn display_json(puzzle_run: &PuzzleRun) {
+    let (status, expected_answer, error) = match &puzzle_run.status {
+        RunStatus::Correct => ("correct", None, None),
+        RunStatus::Wrong(expected_answer) => ("wrong", Some(expected_answer.as_str()), None),
+        RunStatus::Unverified => ("unverified", None, None),
+        RunStatus::Failed(message) => ("failed", None, Some(message.as_str())),
+    };
+
+    let report = PuzzleRunReport {
+        year: puzzle_run.year,
+        day: puzzle_run.day,
+        part: puzzle_run.part,
+        input: &puzzle_run.input_name,
+        answer: puzzle_run.answer.as_deref(),
+        duration_ms: get_duration_ms(puzzle_run.duration),
+        status,
+        expected_answer,
+        error,
+    };
+
+    println!("{}", serde_json::to_string(&report).unwrap());
+}
+
+/// Append the durations of the successful runs to the history file
+fn record_history(puzzle_runs: &[PuzzleRun]) {
+    let records = puzzle_runs
+        .iter()
+        .filter(|puzzle_run| !has_failed(puzzle_run))
+        .map(|puzzle_run| {
+            HistoryRecord::new(
+                puzzle_run.year,
+                puzzle_run.day,
+                puzzle_run.part,
+                &puzzle_run.input_name,
+                Measurement::Run,
+                get_duration_ms(puzzle_run.duration),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    history::append(&records);
+}
+
+/// Duration in milliseconds, with a microsecond precision
+fn get_duration_ms(duration: Duration) -> f64 {
+    match duration.num_microseconds() {
+        Some(microseconds) => microseconds as f64 / 1000.0,
+        None => duration.num_milliseconds() as f64,
+    }
 }
 
-use crate::day01::run as day01;
-use crate::day02::run as day02;
-use crate::day03::run as day03;
-use crate::day04::run as day04;
-use crate::day05::run as day05;
-use crate::day06::run as day06;
-use crate::day07::run as day07;
-use crate::day08::run as day08;
-use crate::day09::run as day09;
-use crate::day10::run as day10;
-use crate::day11::run as day11;
-use crate::day12::run as day12;
-use crate::day13::run as day13;
-use crate::day14::run as day14;
-use crate::day15::run as day15;
-use crate::day16::run as day16;
-use crate::day17::run as day17;
-use crate::day18::run as day18;
-use crate::day19::run as day19;
-use crate::day20::run as day20;
-use crate::day21::run as day21;
-use crate::day22::run as day22;
-use crate::day23::run as day23;
-use crate::day24::run as day24;
-use crate::day25::run as day25;
+/// Multi-line answers (like the CRT screen of the day 10) cannot fit in the summary table
+fn get_single_line(answer: &str) -> String {
+    match answer.contains('\n') {
+        true => String::from("(multi-line)"),
+        false => answer.to_string(),
+    }
+}
+
+pub fn display_info(year: u16, day: u8, part: u8, use_sample: bool) {
+    println!("====  Year {}  ====", year);
+    println!("====  Day {}  ====", day);
+    println!("====  Part {}  ====", part);
+
+    if use_sample {
+        println!(r"/!\ Sample data /!\");
+    }
+}
+
+/// Exit with an error message if the puzzle does not have the given part
+/// Find a puzzle, exiting with an error code if the day has not been generated
+pub fn get_puzzle(year: u16, day: u8) -> &'static Puzzle {
+    match days::find_puzzle(year, day) {
+        Some(puzzle) => puzzle,
+        None => {
+            println!("Invalid day number. Did you forget to generate this day using the script?");
+            process::exit(1);
+        }
+    }
+}
+
+pub fn check_part(puzzle: &Puzzle, part: u8) {
+    if !puzzle.has_part(part) {
+        println!(
+            "Invalid part number {}, day {} of {} only has {} part(s).",
+            part, puzzle.day, puzzle.year, puzzle.parts
+        );
+        process::exit(1);
+    }
+}
+
+/// Read the content of an input file, or the standard input if the path is `-`
+fn read_input_file(input_path: &str) -> String {
+    let result = match input_path {
+        "-" => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map(|_| input)
+        }
+        _ => fs::read_to_string(config::resolve_user_path(input_path)),
+    };
+
+    match result {
+        Ok(input) => input,
+        Err(error) => {
+            println!(
+                "Cannot read input from {}: {}",
+                get_input_name(input_path),
+                error
+            );
+            process::exit(1);
+        }
+    }
+}
+
+fn get_embedded_input_name(use_sample: bool) -> &'static str {
+    if use_sample {
+        "sample"
+    } else {
+        "input"
+    }
+}
+
+fn get_input_name(input_path: &str) -> &str {
+    match input_path {
+        "-" => "stdin",
+        _ => input_path,
+    }
+}
+
+/// Read a default value from the env variables, set in the `.cargo/config.toml` file
+pub fn read_env_variable<T: FromStr>(name: &str) -> T {
+    match read_optional_env_variable(name) {
+        Some(value) => value,
+        None => {
+            println!(
+                "Missing {} value. Pass it to the run command or set it in the .cargo/config.toml file.",
+                name
+            );
+            process::exit(1);
+        }
+    }
+}
+
+/// Read a default value from the env variables, which may not be set outside of `cargo run`
+pub fn read_optional_env_variable<T: FromStr>(name: &str) -> Option<T> {
+    let value = env::var(name).ok()?;
+
+    match value.parse::<T>() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            println!("Invalid {} value: {}", name, value);
+            process::exit(1);
+        }
+    }
+}
+
+/// Whether to use the sample, the USE_SAMPLE env variable being only a default (the input otherwise)
+pub fn read_use_sample(use_sample: Option<bool>) -> bool {
+    use_sample
+        .or_else(|| read_optional_env_variable("USE_SAMPLE"))
+        .unwrap_or(false)
+}
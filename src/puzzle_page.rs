@@ -0,0 +1,93 @@
+use regex::Regex;
+
+/// Extract the title of the puzzle, from the `--- Day 1: Calorie Counting ---` heading
+pub fn extract_title(html: &str) -> Option<String> {
+    let regex = Regex::new(r"<h2>--- Day \d+: (.*?) ---</h2>").unwrap();
+    let title = regex.captures(html)?.get(1)?.as_str();
+
+    Some(decode_html(title))
+}
+
+/// Extract the sample input of the puzzle, which is the first `<pre><code>` block of the page
+pub fn extract_sample(html: &str) -> Option<String> {
+    let regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let sample = regex.captures(html)?.get(1)?.as_str();
+
+    Some(decode_html(sample))
+}
+
+/// Extract the answer of the example, for each part of the puzzle described in the page.
+/// The answer is usually the last emphasized code of the part description.
+pub fn extract_example_answers(html: &str) -> Vec<String> {
+    let article_regex = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    let emphasized_code_regex = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+    let emphasized_regex = Regex::new(r"(?s)<em>(.*?)</em>").unwrap();
+
+    article_regex
+        .captures_iter(html)
+        .filter_map(|captures| {
+            let article = captures.get(1)?.as_str();
+
+            let last_emphasized_code = emphasized_code_regex.captures_iter(article).last();
+            let last_emphasized = emphasized_regex.captures_iter(article).last();
+
+            let answer = last_emphasized_code.or(last_emphasized)?.get(1)?.as_str();
+
+            Some(decode_html(answer))
+        })
+        .collect()
+}
+
+/// Remove the HTML tags and decode the HTML entities
+fn decode_html(html: &str) -> String {
+    let tag_regex = Regex::new(r"<[^>]*>").unwrap();
+    let text = tag_regex.replace_all(html, "");
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>3000</em> &lt;&amp;&gt;
+</code></pre>
+<p>The answer is <code><em>24000</em></code>, <em>not</em> the total.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>In the example above, the top three Elves carry <em>45000</em> Calories.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extract_title_from_heading() {
+        assert_eq!(extract_title(PAGE).unwrap(), "Calorie Counting");
+    }
+
+    #[test]
+    fn extract_first_code_block_as_sample() {
+        assert_eq!(extract_sample(PAGE).unwrap(), "1000\n2000\n\n3000 <&>\n");
+    }
+
+    #[test]
+    fn extract_answer_of_each_part() {
+        assert_eq!(extract_example_answers(PAGE), vec!["24000", "45000"]);
+    }
+
+    #[test]
+    fn extract_nothing_from_unknown_page() {
+        assert_eq!(extract_title("<html></html>"), None);
+        assert_eq!(extract_sample("<html></html>"), None);
+        assert!(extract_example_answers("<html></html>").is_empty());
+    }
+}
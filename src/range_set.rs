@@ -0,0 +1,212 @@
+use crate::geometry::Integer;
+use std::ops::RangeInclusive;
+
+/// Set of integers stored as sorted ranges, overlapping or adjacent ranges being merged together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> RangeSet<T> {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> T {
+        self.ranges.iter().fold(T::ZERO, |len, range| {
+            len + *range.end() - *range.start() + T::ONE
+        })
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    /// Disjoint ranges of the set, in ascending order
+    pub fn ranges(&self) -> impl Iterator<Item = &RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+
+    /// Add every value of the range, merging it with the ranges it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let (mut start, mut end) = range.into_inner();
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+
+        for existing_range in self.ranges.drain(..) {
+            // a range ending at the maximum value of the type has no adjacent range after it
+            let is_before = existing_range
+                .end()
+                .checked_add(T::ONE)
+                .is_some_and(|after_end| after_end < start);
+            let is_after = end
+                .checked_add(T::ONE)
+                .is_some_and(|after_end| after_end < *existing_range.start());
+
+            if is_before || is_after {
+                ranges.push(existing_range);
+            } else {
+                start = start.min(*existing_range.start());
+                end = end.max(*existing_range.end());
+            }
+        }
+
+        ranges.push(start..=end);
+        ranges.sort_by_key(|range| *range.start());
+
+        self.ranges = ranges;
+    }
+
+    /// Remove every value of the range, splitting the ranges it cuts through
+    pub fn subtract(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let (start, end) = range.into_inner();
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+
+        for existing_range in self.ranges.drain(..) {
+            let (existing_start, existing_end) = existing_range.into_inner();
+
+            if let Some(before_start) = start.checked_sub(T::ONE) {
+                if existing_start <= before_start {
+                    ranges.push(existing_start..=existing_end.min(before_start));
+                }
+            }
+
+            if let Some(after_end) = end.checked_add(T::ONE) {
+                if existing_end >= after_end {
+                    ranges.push(existing_start.max(after_end)..=existing_end);
+                }
+            }
+        }
+
+        self.ranges = ranges;
+    }
+
+    /// Values in either set
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+
+        for range in other.ranges() {
+            union.insert(range.clone());
+        }
+
+        union
+    }
+
+    /// Values in both sets
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut intersection = RangeSet::new();
+
+        for range in self.ranges() {
+            for other_range in other.ranges() {
+                let start = *range.start().max(other_range.start());
+                let end = *range.end().min(other_range.end());
+
+                intersection.insert(start..=end);
+            }
+        }
+
+        intersection
+    }
+
+    /// Values of the given range missing from the set
+    pub fn gaps(&self, within: RangeInclusive<T>) -> RangeSet<T> {
+        let mut gaps = RangeSet::from_iter([within]);
+
+        for range in self.ranges() {
+            gaps.subtract(range.clone());
+        }
+
+        gaps
+    }
+}
+
+impl<T: Integer> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet::new()
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &RangeSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.ranges().cloned().collect()
+    }
+
+    #[test]
+    fn merge_overlapping_and_adjacent_ranges() {
+        let set = RangeSet::from_iter([5..=8, -2..=1, 0..=3, 10..=12, 9..=9]);
+
+        assert_eq!(ranges(&set), vec![-2..=3, 5..=12]);
+        assert_eq!(set.len(), 14);
+        assert!(set.contains(9));
+        assert!(!set.contains(4));
+    }
+
+    #[test]
+    fn subtract_and_find_gaps() {
+        let mut set = RangeSet::from_iter([0..=10]);
+        set.subtract(3..=4);
+        set.subtract(10..=15);
+
+        assert_eq!(ranges(&set), vec![0..=2, 5..=9]);
+        assert_eq!(ranges(&set.gaps(-1..=12)), vec![-1..=-1, 3..=4, 10..=12]);
+    }
+
+    #[test]
+    fn combine_sets() {
+        let one = RangeSet::from_iter([0..=4, 8..=10]);
+        let two = RangeSet::from_iter([3..=8]);
+
+        assert_eq!(ranges(&one.union(&two)), vec![0..=10]);
+        assert_eq!(ranges(&one.intersection(&two)), vec![3..=4, 8..=8]);
+        assert!(one.intersection(&RangeSet::from_iter([5..=7])).is_empty());
+    }
+
+    #[test]
+    fn stay_within_type_bounds() {
+        let mut set = RangeSet::from_iter([250..=u8::MAX, 0..=3, 240..=249]);
+
+        assert_eq!(
+            set.ranges().cloned().collect::<Vec<_>>(),
+            vec![0..=3, 240..=u8::MAX]
+        );
+
+        set.subtract(0..=1);
+        set.subtract(u8::MAX..=u8::MAX);
+
+        assert_eq!(
+            set.ranges().cloned().collect::<Vec<_>>(),
+            vec![2..=3, 240..=254]
+        );
+        assert_eq!(
+            set.gaps(0..=u8::MAX).ranges().cloned().collect::<Vec<_>>(),
+            vec![0..=1, 4..=239, u8::MAX..=u8::MAX]
+        );
+    }
+}
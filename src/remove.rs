@@ -0,0 +1,165 @@
+use crate::{change_plan::ChangePlan, config, days, generate, template};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// Remove the folder of a day generated from a template, the reverse of `generate`.
+/// Days are registered from their folder at build time, so no source file needs to be edited.
+pub fn run(year: Option<u16>, day: u8, force: bool, dry_run: bool) {
+    let year = config::get_year(year);
+    let day_path = days::get_day_path(year, day);
+
+    if !day_path.exists() {
+        println!("Folder {} does not exist!", day_path.display());
+        process::exit(1);
+    }
+
+    if !force {
+        check_no_changes_from_template(year, day, &day_path);
+    }
+
+    let plan = match plan_removal(year, day, &day_path) {
+        Ok(plan) => plan,
+        Err(error) => {
+            println!("Cannot remove folder {}: {}", day_path.display(), error);
+            process::exit(1);
+        }
+    };
+
+    if dry_run {
+        plan.display_diff();
+        return;
+    }
+
+    if let Err(error) = plan.apply() {
+        println!("Cannot remove folder {}: {}", day_path.display(), error);
+        process::exit(1);
+    }
+
+    println!("Folder {} successfully removed!", day_path.display());
+}
+
+fn plan_removal(year: u16, day: u8, day_path: &Path) -> Result<ChangePlan, String> {
+    let mut plan = ChangePlan::default();
+
+    for path in list_files(day_path).map_err(|error| error.to_string())? {
+        plan.delete(&path)
+            .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+    }
+
+    restore_config_file(&mut plan, year, day)?;
+
+    Ok(plan)
+}
+
+/// Refuse to remove a day whose files are not the ones of a template anymore,
+/// so that a solution is never lost by mistake
+fn check_no_changes_from_template(year: u16, day: u8, day_path: &Path) {
+    let changed_files = match get_changed_files(year, day, day_path) {
+        Ok(changed_files) => changed_files,
+        Err(error) => {
+            println!(
+                "Cannot compare {} with the template: {}",
+                day_path.display(),
+                error
+            );
+            process::exit(1);
+        }
+    };
+
+    if !changed_files.is_empty() {
+        println!(
+            "Folder {} has changes from the template, use --force to remove it anyway:",
+            day_path.display()
+        );
+        for path in changed_files {
+            println!("  {}", path.display());
+        }
+        process::exit(1);
+    }
+}
+
+/// Files of the day folder which differ from the same file of every template
+fn get_changed_files(year: u16, day: u8, day_path: &Path) -> io::Result<Vec<PathBuf>> {
+    let template_folders = template::get_template_folders();
+
+    let mut changed_files = Vec::new();
+
+    for path in list_files(day_path)? {
+        let relative_path = path.strip_prefix(day_path).unwrap();
+        let content = fs::read_to_string(&path)?;
+
+        let is_template_file =
+            template_folders.iter().any(|template_path| {
+                match fs::read_to_string(template_path.join(relative_path)) {
+                    Ok(template_content) => {
+                        template::is_rendered_from(&template_content, &content, year, day)
+                    }
+                    Err(_) => false,
+                }
+            });
+
+        if !is_template_file {
+            changed_files.push(path);
+        }
+    }
+
+    Ok(changed_files)
+}
+
+/// Point the config to the latest remaining day (of the same year if any) if it was set to the removed one
+fn restore_config_file(plan: &mut ChangePlan, year: u16, day: u8) -> Result<(), String> {
+    let config_path = config::get().paths.env_config.as_path();
+    let config_content = fs::read_to_string(config_path)
+        .map_err(|error| format!("cannot read {}: {}", config_path.display(), error))?;
+
+    let is_removed_day = config_content.contains(&format!("YEAR = \"{}\"", year))
+        && config_content.contains(&format!("DAY = \"{}\"", day));
+    if !is_removed_day {
+        return Ok(());
+    }
+
+    let latest_puzzle = days::PUZZLES
+        .iter()
+        .filter(|puzzle| puzzle.year != year || puzzle.day != day)
+        .max_by_key(|puzzle| (puzzle.year == year, puzzle.year, puzzle.day));
+
+    let latest_puzzle = match latest_puzzle {
+        Some(latest_puzzle) => latest_puzzle,
+        None => return Ok(()),
+    };
+
+    let config_content = generate::replace_config_value(
+        &config_content,
+        r#"YEAR = "\d+""#,
+        &format!("YEAR = \"{}\"", latest_puzzle.year),
+    )?;
+    let config_content = generate::replace_config_value(
+        &config_content,
+        r#"DAY = "\d+""#,
+        &format!("DAY = \"{}\"", latest_puzzle.day),
+    )?;
+
+    plan.modify(config_path, config_content)
+        .map_err(|error| format!("cannot read {}: {}", config_path.display(), error))
+}
+
+fn list_files(path: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+
+        if entry.file_type()?.is_dir() {
+            files.extend(list_files(&entry.path())?);
+        } else {
+            files.push(entry.path());
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
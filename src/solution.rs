@@ -0,0 +1,76 @@
+use crate::parse_error::ParseError;
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// Solution of a puzzle, split between the parsing of the input and the resolution of each part.
+/// The answers are returned instead of being printed, so they can be used by the runner.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Number of parts of the puzzle (the last day only has a single part)
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parse the sample, which some puzzles solve with other parameters than the real input
+    fn parse_sample(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// Durations of a single run, separating the parsing of the input from the resolution of the part
+#[derive(Clone, Copy, Debug)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Answer of a part, along with the durations of the run
+pub type TimedAnswer = (String, Timings);
+
+/// Parse the input and solve the given part of the puzzle
+pub fn solve<S: Solution>(part: u8, input: &str, use_sample: bool) -> Result<String, ParseError> {
+    let input = parse::<S>(input, use_sample)?;
+
+    Ok(solve_part::<S>(part, &input))
+}
+
+/// Parse the input and solve the given part of the puzzle, measuring the duration of each step
+pub fn solve_timed<S: Solution>(
+    part: u8,
+    input: &str,
+    use_sample: bool,
+) -> Result<TimedAnswer, ParseError> {
+    let before = Instant::now();
+    let input = parse::<S>(input, use_sample)?;
+    let parse = before.elapsed();
+
+    let before = Instant::now();
+    let answer = solve_part::<S>(part, &input);
+    let solve = before.elapsed();
+
+    Ok((answer, Timings { parse, solve }))
+}
+
+fn parse<S: Solution>(input: &str, use_sample: bool) -> Result<S::Input, ParseError> {
+    if use_sample {
+        S::parse_sample(input)
+    } else {
+        S::parse(input)
+    }
+}
+
+fn solve_part<S: Solution>(part: u8, input: &S::Input) -> String {
+    match part {
+        1 => S::part1(input).to_string(),
+        2 if S::PARTS >= 2 => S::part2(input).to_string(),
+        _ => panic!("Invalid part number"),
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{
+    answers::Answers,
+    config, days,
+    fetch::Website,
+    puzzle::{check_part, get_puzzle, read_env_variable},
+};
+use chrono::{DateTime, Duration, Utc};
+use colored::Colorize;
+use regex::Regex;
+use std::{fs, process};
+
+const RATE_LIMIT_PATH: &str = ".aoc_rate_limit";
+/// The website refuses any submission made less than a minute after a wrong answer
+const SUBMISSION_DELAY_SECONDS: i64 = 60;
+
+#[derive(Debug, PartialEq, Eq)]
+enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait(Option<Duration>),
+    AlreadySolved,
+    Unknown,
+}
+
+/// Submit the answer of the real input for the given day and part.
+/// The outcome is recorded in the `answers.toml` file of the day, so that a wrong answer is never submitted twice.
+pub fn run(year: Option<u16>, day: Option<u8>, part: Option<u8>) {
+    let year = config::get_year(year);
+    let day = day.unwrap_or_else(|| read_env_variable("DAY"));
+    let part = part.unwrap_or_else(|| read_env_variable("PART"));
+
+    let puzzle = get_puzzle(year, day);
+    check_part(puzzle, part);
+
+    let answer = match (puzzle.solve)(part, puzzle.input, false) {
+        Ok(answer) => answer,
+        Err(error) => {
+            println!("{}", error.with_day(day).render().red());
+            process::exit(1);
+        }
+    };
+    println!("Answer of {} day {} part {}: {}", year, day, part, answer);
+
+    let answers_path = days::get_day_path(year, day).join("answers.toml");
+    let mut answers = Answers::read(&answers_path);
+
+    if let Some(expected_answer) = answers.get_expected_answer(part, false) {
+        if expected_answer == answer {
+            println!("This answer is already known to be correct, there is no need to submit it.");
+            return;
+        }
+
+        println!(
+            "The right answer is already known ({}), this one will not be submitted.",
+            expected_answer
+        );
+        process::exit(1);
+    }
+
+    if answers.get_wrong_answers(part).contains(&answer) {
+        println!("This answer was already rejected, it will not be submitted again.");
+        process::exit(1);
+    }
+
+    check_rate_limit();
+
+    let path = format!("/{}/day/{}/answer", year, day);
+    let level = part.to_string();
+    let response =
+        match Website::from_env().post_form(&path, &[("level", &level), ("answer", &answer)]) {
+            Ok(response) => response,
+            Err(error) => {
+                println!("Cannot submit the answer: {}", error);
+                process::exit(1);
+            }
+        };
+
+    let outcome = parse_response(&response);
+
+    match outcome {
+        SubmissionOutcome::Correct => {
+            answers.set_input_answer(part, &answer);
+            answers.save(&answers_path);
+
+            println!("{}", "✓ That's the right answer!".green());
+            println!("Answer saved in {}", answers_path.display());
+        }
+        SubmissionOutcome::TooHigh | SubmissionOutcome::TooLow | SubmissionOutcome::Wrong => {
+            answers.add_wrong_answer(part, &answer);
+            answers.save(&answers_path);
+            save_rate_limit(Duration::seconds(SUBMISSION_DELAY_SECONDS));
+
+            let message = match outcome {
+                SubmissionOutcome::TooHigh => "✗ That's not the right answer, it is too high.",
+                SubmissionOutcome::TooLow => "✗ That's not the right answer, it is too low.",
+                _ => "✗ That's not the right answer.",
+            };
+            println!("{}", message.red());
+        }
+        SubmissionOutcome::Wait(wait) => {
+            let wait = wait.unwrap_or_else(|| Duration::seconds(SUBMISSION_DELAY_SECONDS));
+            save_rate_limit(wait);
+
+            println!(
+                "{}",
+                format!(
+                    "Answer submitted too recently, please wait {}s before trying again.",
+                    wait.num_seconds()
+                )
+                .yellow()
+            );
+        }
+        SubmissionOutcome::AlreadySolved => {
+            println!("This part is already solved, or not unlocked yet.");
+        }
+        SubmissionOutcome::Unknown => {
+            println!("Unknown response from the website:");
+            println!("{}", response);
+        }
+    }
+
+    if outcome != SubmissionOutcome::Correct {
+        process::exit(1);
+    }
+}
+
+fn parse_response(response: &str) -> SubmissionOutcome {
+    if response.contains("That's the right answer") {
+        return SubmissionOutcome::Correct;
+    }
+    if response.contains("That's not the right answer") {
+        if response.contains("your answer is too high") {
+            return SubmissionOutcome::TooHigh;
+        }
+        if response.contains("your answer is too low") {
+            return SubmissionOutcome::TooLow;
+        }
+        return SubmissionOutcome::Wrong;
+    }
+    if response.contains("You gave an answer too recently") {
+        let regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+
+        let wait = regex.captures(response).map(|captures| {
+            let minutes = captures
+                .get(1)
+                .map(|minutes| minutes.as_str().parse::<i64>().unwrap())
+                .unwrap_or(0);
+            let seconds = captures[2].parse::<i64>().unwrap();
+
+            Duration::seconds(minutes * 60 + seconds)
+        });
+
+        return SubmissionOutcome::Wait(wait);
+    }
+    if response.contains("You don't seem to be solving the right level") {
+        return SubmissionOutcome::AlreadySolved;
+    }
+
+    SubmissionOutcome::Unknown
+}
+
+/// Exit if the last submission asked to wait before submitting again
+fn check_rate_limit() {
+    let next_submission = fs::read_to_string(RATE_LIMIT_PATH)
+        .ok()
+        .and_then(|content| DateTime::parse_from_rfc3339(content.trim()).ok());
+
+    if let Some(next_submission) = next_submission {
+        let wait = next_submission.with_timezone(&Utc) - Utc::now();
+
+        if wait > Duration::zero() {
+            println!(
+                "Please wait {}s before submitting another answer.",
+                wait.num_seconds() + 1
+            );
+            process::exit(1);
+        }
+    }
+}
+
+fn save_rate_limit(wait: Duration) {
+    let next_submission = Utc::now() + wait;
+
+    fs::write(RATE_LIMIT_PATH, next_submission.to_rfc3339()).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_right_answer() {
+        let response =
+            "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+
+        assert_eq!(parse_response(response), SubmissionOutcome::Correct);
+    }
+
+    #[test]
+    fn parse_wrong_answers() {
+        let too_high = "<p>That's not the right answer; your answer is too high.</p>";
+        let too_low = "<p>That's not the right answer; your answer is too low.</p>";
+        let wrong = "<p>That's not the right answer. If you're stuck, make sure...</p>";
+
+        assert_eq!(parse_response(too_high), SubmissionOutcome::TooHigh);
+        assert_eq!(parse_response(too_low), SubmissionOutcome::TooLow);
+        assert_eq!(parse_response(wrong), SubmissionOutcome::Wrong);
+    }
+
+    #[test]
+    fn parse_wait_duration() {
+        let response = "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 12s left to wait.</p>";
+        let short_response = "<p>You gave an answer too recently. You have 42s left to wait.</p>";
+
+        assert_eq!(
+            parse_response(response),
+            SubmissionOutcome::Wait(Some(Duration::seconds(72)))
+        );
+        assert_eq!(
+            parse_response(short_response),
+            SubmissionOutcome::Wait(Some(Duration::seconds(42)))
+        );
+    }
+
+    #[test]
+    fn parse_unknown_response() {
+        assert_eq!(parse_response("<html></html>"), SubmissionOutcome::Unknown);
+    }
+}
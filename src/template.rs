@@ -0,0 +1,204 @@
+use crate::config;
+use regex::{Captures, Regex};
+use std::{fs, path::PathBuf};
+
+/// Values of the `{{name}}` placeholders of the template files
+pub struct TemplateVariables {
+    pub year: u16,
+    pub day: u8,
+    pub title: Option<String>,
+    pub sample_answer: Option<String>,
+}
+
+impl TemplateVariables {
+    fn get(&self, name: &str) -> Option<String> {
+        match name {
+            "day" => Some(self.day.to_string()),
+            "day_padded" => Some(format!("{:02}", self.day)),
+            "year" => Some(self.year.to_string()),
+            "title" => Some(
+                self.title
+                    .clone()
+                    .unwrap_or_else(|| String::from("Untitled")),
+            ),
+            "sample_answer" => Some(self.sample_answer.clone().unwrap_or_default()),
+            "result_type" => Some(get_result_type(self.sample_answer.as_deref()).to_string()),
+            _ => None,
+        }
+    }
+
+    /// Replace every known placeholder of the content by its value, unknown ones are kept as is
+    pub fn render(&self, content: &str) -> String {
+        get_placeholder_regex()
+            .replace_all(content, |captures: &Captures| {
+                self.get(&captures[1])
+                    .unwrap_or_else(|| captures[0].to_string())
+            })
+            .to_string()
+    }
+}
+
+/// Type of the answer of the first part, guessed from the answer of its example (a number by default)
+fn get_result_type(sample_answer: Option<&str>) -> &'static str {
+    match sample_answer {
+        Some(answer) if answer.parse::<u64>().is_ok() => "u64",
+        Some(answer) if answer.parse::<i64>().is_ok() => "i64",
+        Some(_) => "String",
+        None => "u64",
+    }
+}
+
+/// Check if the content has been rendered from the template for the given day.
+/// The placeholders extracted from the puzzle page (title, sample answer, result type) can have any value.
+pub fn is_rendered_from(template: &str, content: &str, year: u16, day: u8) -> bool {
+    let variables = TemplateVariables {
+        year,
+        day,
+        title: None,
+        sample_answer: None,
+    };
+
+    let mut pattern = String::from("^");
+    let mut last_end = 0;
+
+    for captures in get_placeholder_regex().captures_iter(template) {
+        let placeholder = captures.get(0).unwrap();
+        pattern.push_str(&regex::escape(&template[last_end..placeholder.start()]));
+
+        match &captures[1] {
+            "title" | "sample_answer" | "result_type" => pattern.push_str("[^\n]*"),
+            name => {
+                let value = variables
+                    .get(name)
+                    .unwrap_or_else(|| placeholder.as_str().to_string());
+                pattern.push_str(&regex::escape(&value));
+            }
+        }
+
+        last_end = placeholder.end();
+    }
+
+    pattern.push_str(&regex::escape(&template[last_end..]));
+    pattern.push('$');
+
+    Regex::new(&pattern).unwrap().is_match(content)
+}
+
+fn get_placeholder_regex() -> Regex {
+    Regex::new(r"\{\{(\w+)\}\}").unwrap()
+}
+
+/// Folder of the given template, looked up in the user templates folder first, then in the built-in one
+pub fn find_template(name: &str) -> Option<PathBuf> {
+    get_templates_folders()
+        .into_iter()
+        .map(|templates_path| templates_path.join(name))
+        .find(|template_path| template_path.is_dir())
+}
+
+/// Folders of every available template
+pub fn get_template_folders() -> Vec<PathBuf> {
+    let mut template_folders = Vec::new();
+
+    for templates_path in get_templates_folders() {
+        if let Ok(entries) = fs::read_dir(templates_path) {
+            for entry in entries.flatten() {
+                if entry.path().is_dir() {
+                    template_folders.push(entry.path());
+                }
+            }
+        }
+    }
+
+    template_folders.sort();
+
+    template_folders
+}
+
+pub fn get_template_names() -> Vec<String> {
+    let mut names = get_template_folders()
+        .iter()
+        .filter_map(|template_path| template_path.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+
+    names.sort();
+    names.dedup();
+
+    names
+}
+
+fn get_templates_folders() -> Vec<PathBuf> {
+    let paths = &config::get().paths;
+
+    let mut templates_folders = Vec::new();
+
+    if let Some(user_templates_path) = &paths.user_templates {
+        templates_folders.push(user_templates_path.clone());
+    }
+    templates_folders.push(paths.templates.clone());
+
+    templates_folders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = "//! Day {{day}}: {{title}}\n// src/day{{day_padded}} {{unknown}}\n";
+
+    #[test]
+    fn render_placeholders() {
+        let variables = TemplateVariables {
+            year: 2022,
+            day: 1,
+            title: Some(String::from("Calorie Counting")),
+            sample_answer: Some(String::from("24000")),
+        };
+
+        assert_eq!(
+            variables.render(TEMPLATE),
+            "//! Day 1: Calorie Counting\n// src/day01 {{unknown}}\n"
+        );
+        assert_eq!(
+            variables.render("{{year}} {{sample_answer}} {{result_type}}"),
+            "2022 24000 u64"
+        );
+    }
+
+    #[test]
+    fn render_missing_page_placeholders() {
+        let variables = TemplateVariables {
+            year: 2022,
+            day: 1,
+            title: None,
+            sample_answer: None,
+        };
+
+        assert_eq!(
+            variables.render("{{title}}: {{sample_answer}} {{result_type}}"),
+            "Untitled:  u64"
+        );
+    }
+
+    #[test]
+    fn guess_result_type() {
+        assert_eq!(get_result_type(Some("24000")), "u64");
+        assert_eq!(get_result_type(Some("-3")), "i64");
+        assert_eq!(get_result_type(Some("CMZ")), "String");
+    }
+
+    #[test]
+    fn recognize_rendered_template() {
+        let content = "//! Day 1: Calorie Counting\n// src/day01 {{unknown}}\n";
+
+        assert!(is_rendered_from(TEMPLATE, content, 2022, 1));
+        assert!(!is_rendered_from(TEMPLATE, content, 2022, 2));
+        assert!(!is_rendered_from(
+            TEMPLATE,
+            &format!("{}// changed\n", content),
+            2022,
+            1
+        ));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::parse_error::{self, ParseError};
+
+pub type Calories = i32;
+pub type Elf = Vec<Calories>;
+pub type Input = Vec<Elf>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut elves = vec![vec![]];
+
+    for line in parse_error::lines(input) {
+        if line.text.is_empty() {
+            elves.push(vec![]);
+            continue;
+        }
+
+        let calories = line.parse::<Calories>(line.text)?;
+        let current_elf = elves.last_mut().unwrap();
+
+        current_elf.push(calories);
+    }
+
+    Ok(elves)
+}
@@ -0,0 +1,12 @@
+use super::input::{Calories, Input};
+
+pub type PuzzleResult = Calories;
+
+pub fn get_result(elves: &Input) -> PuzzleResult {
+    let calories_list = elves
+        .iter()
+        .map(|elf| elf.iter().sum::<Calories>())
+        .collect::<Vec<_>>();
+
+    *calories_list.iter().max().unwrap()
+}
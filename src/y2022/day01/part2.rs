@@ -0,0 +1,16 @@
+use super::input::{Calories, Input};
+use itertools::Itertools;
+
+pub type PuzzleResult = Calories;
+
+pub fn get_result(elves: &Input) -> PuzzleResult {
+    let calories_list = elves
+        .iter()
+        .map(|elf| elf.iter().sum::<Calories>())
+        .collect::<Vec<_>>();
+
+    let sorted_calories_list = calories_list.into_iter().sorted().rev().collect::<Vec<_>>();
+    let top3_calories = sorted_calories_list.into_iter().take(3).collect::<Vec<_>>();
+
+    top3_calories.iter().sum::<Calories>()
+}
@@ -0,0 +1,52 @@
+use crate::parse_error::{self, ParseError};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// Second column of the strategy guide, its meaning differs between the two parts
+#[derive(Debug, Clone, Copy)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug)]
+pub struct Round {
+    pub opponent: Shape,
+    pub response: Response,
+}
+
+pub type Input = Vec<Round>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut rounds = Vec::new();
+
+    for line in parse_error::lines(input) {
+        let mut shapes = line.text.split_whitespace();
+
+        let opponent = match shapes.next() {
+            Some("A") => Shape::Rock,
+            Some("B") => Shape::Paper,
+            Some("C") => Shape::Scissors,
+            Some(shape) => return Err(line.error(shape, "expected A, B or C")),
+            None => return Err(line.missing("expected A, B or C")),
+        };
+
+        let response = match shapes.next() {
+            Some("X") => Response::X,
+            Some("Y") => Response::Y,
+            Some("Z") => Response::Z,
+            Some(shape) => return Err(line.error(shape, "expected X, Y or Z")),
+            None => return Err(line.missing("expected X, Y or Z")),
+        };
+
+        rounds.push(Round { opponent, response });
+    }
+
+    Ok(rounds)
+}
@@ -0,0 +1,51 @@
+use super::input::{Input, Response, Shape};
+
+#[derive(Debug)]
+enum RoundResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(rounds: &Input) -> PuzzleResult {
+    let scores = rounds.iter().map(|round| {
+        let player = match round.response {
+            Response::X => Shape::Rock,
+            Response::Y => Shape::Paper,
+            Response::Z => Shape::Scissors,
+        };
+
+        let round_result = get_round_result(&player, &round.opponent);
+
+        let outcome_score = match round_result {
+            RoundResult::Win => 6,
+            RoundResult::Draw => 3,
+            RoundResult::Loss => 0,
+        };
+
+        let shape_score = match player {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        };
+
+        outcome_score + shape_score
+    });
+
+    scores.sum::<PuzzleResult>()
+}
+
+fn get_round_result(player: &Shape, opponent: &Shape) -> RoundResult {
+    if opponent == player {
+        return RoundResult::Draw;
+    }
+    if (opponent == &Shape::Rock && player == &Shape::Paper)
+        || (opponent == &Shape::Paper && player == &Shape::Scissors)
+        || (opponent == &Shape::Scissors && player == &Shape::Rock)
+    {
+        return RoundResult::Win;
+    }
+    RoundResult::Loss
+}
@@ -0,0 +1,54 @@
+use super::input::{Input, Response, Shape};
+
+#[derive(Debug)]
+enum RoundResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(rounds: &Input) -> PuzzleResult {
+    let scores = rounds.iter().map(|round| {
+        let result = match round.response {
+            Response::X => RoundResult::Loss,
+            Response::Y => RoundResult::Draw,
+            Response::Z => RoundResult::Win,
+        };
+
+        let shape = get_player_shape(&result, round.opponent);
+
+        let outcome_score = match result {
+            RoundResult::Win => 6,
+            RoundResult::Draw => 3,
+            RoundResult::Loss => 0,
+        };
+
+        let shape_score = match shape {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        };
+
+        outcome_score + shape_score
+    });
+
+    scores.sum::<PuzzleResult>()
+}
+
+fn get_player_shape(result: &RoundResult, opponent: Shape) -> Shape {
+    match result {
+        RoundResult::Win => match opponent {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        },
+        RoundResult::Draw => opponent,
+        RoundResult::Loss => match opponent {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        },
+    }
+}
@@ -0,0 +1,8 @@
+use crate::parse_error::ParseError;
+
+pub type Rucksack = String;
+pub type Input = Vec<Rucksack>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    Ok(input.lines().map(|line| line.to_string()).collect())
+}
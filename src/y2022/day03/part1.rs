@@ -1,14 +1,16 @@
+use super::input::Input;
+
 #[derive(Debug)]
 struct Rucksack {
     compartment_one: String,
     compartment_two: String,
 }
 
-pub fn run(input: &str) {
-    let lines = input.lines();
+pub type PuzzleResult = u32;
 
+pub fn get_result(input: &Input) -> PuzzleResult {
     let mut rucksacks = Vec::new();
-    for line in lines {
+    for line in input {
         let length = line.len();
         let half_length = length / 2;
 
@@ -27,17 +29,13 @@ pub fn run(input: &str) {
             let compartment_one_chars = r.compartment_one.chars().collect::<Vec<_>>();
             let compartment_two_chars = r.compartment_two.chars().collect::<Vec<_>>();
 
-            return find_char_in_both_compartments(compartment_one_chars, compartment_two_chars);
+            find_char_in_both_compartments(compartment_one_chars, compartment_two_chars)
         })
         .collect::<Vec<_>>();
 
-    let items_priority = items.iter().map(|item| {
-        return get_item_priority(&item);
-    });
-
-    let total_priorities = items_priority.sum::<u32>();
+    let items_priority = items.iter().map(get_item_priority);
 
-    println!("{:?}", total_priorities);
+    items_priority.sum::<PuzzleResult>()
 }
 
 fn find_char_in_both_compartments(
@@ -62,11 +60,11 @@ fn get_item_priority(item: &char) -> u32 {
         const START_ASCII_INDEX: u32 = 97;
         const START_VALUE: u32 = 1;
 
-        return ascii_value - START_ASCII_INDEX + START_VALUE;
+        ascii_value - START_ASCII_INDEX + START_VALUE
     } else {
         const START_ASCII_INDEX: u32 = 65;
         const START_VALUE: u32 = 27;
 
-        return ascii_value - START_ASCII_INDEX + START_VALUE;
+        ascii_value - START_ASCII_INDEX + START_VALUE
     }
 }
@@ -1,3 +1,4 @@
+use super::input::Input;
 use itertools::Itertools;
 
 #[derive(Debug)]
@@ -7,10 +8,11 @@ struct Group {
     rucksack_three: String,
 }
 
-pub fn run(input: &str) {
-    let lines = input.lines();
+pub type PuzzleResult = u32;
 
-    let groups = lines
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let groups = input
+        .iter()
         .chunks(3)
         .into_iter()
         .map(|chunk| {
@@ -20,28 +22,19 @@ pub fn run(input: &str) {
             let rucksack_two = lines[1].to_string();
             let rucksack_three = lines[2].to_string();
 
-            return Group {
+            Group {
                 rucksack_one,
                 rucksack_two,
                 rucksack_three,
-            };
-        })
-        .collect::<Vec<_>>();
-
-    let items = groups
-        .iter()
-        .map(|group| {
-            return find_char_in_group(group);
+            }
         })
         .collect::<Vec<_>>();
 
-    let items_priority = items.iter().map(|item| {
-        return get_item_priority(&item);
-    });
+    let items = groups.iter().map(find_char_in_group).collect::<Vec<_>>();
 
-    let total_priorities = items_priority.sum::<u32>();
+    let items_priority = items.iter().map(get_item_priority);
 
-    println!("{:?}", total_priorities);
+    items_priority.sum::<PuzzleResult>()
 }
 
 fn find_char_in_group(group: &Group) -> char {
@@ -73,11 +66,11 @@ fn get_item_priority(item: &char) -> u32 {
         const START_ASCII_INDEX: u32 = 97;
         const START_VALUE: u32 = 1;
 
-        return ascii_value - START_ASCII_INDEX + START_VALUE;
+        ascii_value - START_ASCII_INDEX + START_VALUE
     } else {
         const START_ASCII_INDEX: u32 = 65;
         const START_VALUE: u32 = 27;
 
-        return ascii_value - START_ASCII_INDEX + START_VALUE;
+        ascii_value - START_ASCII_INDEX + START_VALUE
     }
 }
@@ -0,0 +1,45 @@
+use crate::{
+    parse_error::{self, InputLine, ParseError},
+    range_set::RangeSet,
+};
+
+/// Sections assigned to one elf of the pair
+pub type Pair = RangeSet<u32>;
+
+#[derive(Debug)]
+pub struct Assignment {
+    pub pair_one: Pair,
+    pub pair_two: Pair,
+}
+
+pub type Input = Vec<Assignment>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut assignments = Vec::new();
+
+    for line in parse_error::lines(input) {
+        let (pair_one, pair_two) = line
+            .text
+            .split_once(',')
+            .ok_or_else(|| line.missing("expected two pairs separated by a comma"))?;
+
+        let pair_one = parse_pair(&line, pair_one)?;
+        let pair_two = parse_pair(&line, pair_two)?;
+
+        let assignment = Assignment { pair_one, pair_two };
+        assignments.push(assignment);
+    }
+
+    Ok(assignments)
+}
+
+fn parse_pair(line: &InputLine, value: &str) -> Result<Pair, ParseError> {
+    let (min, max) = value
+        .split_once('-')
+        .ok_or_else(|| line.error(value, "expected a range like 2-4"))?;
+
+    let min = line.parse::<u32>(min)?;
+    let max = line.parse::<u32>(max)?;
+
+    Ok(Pair::from_iter([min..=max]))
+}
@@ -0,0 +1,20 @@
+use super::input::{Assignment, Input};
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(assignments: &Input) -> PuzzleResult {
+    let fully_contained_assigments = assignments
+        .iter()
+        .filter(|assignment| fully_contains(assignment));
+
+    fully_contained_assigments.count()
+}
+
+fn fully_contains(assignment: &Assignment) -> bool {
+    let pair_one = &assignment.pair_one;
+    let pair_two = &assignment.pair_two;
+
+    let all_sections = pair_one.union(pair_two);
+
+    &all_sections == pair_one || &all_sections == pair_two
+}
@@ -0,0 +1,18 @@
+use super::input::{Assignment, Input};
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(assignments: &Input) -> PuzzleResult {
+    let fully_contained_assigments = assignments
+        .iter()
+        .filter(|assignment| partially_contains(assignment));
+
+    fully_contained_assigments.count()
+}
+
+fn partially_contains(assignment: &Assignment) -> bool {
+    let pair_one = &assignment.pair_one;
+    let pair_two = &assignment.pair_two;
+
+    !pair_one.intersection(pair_two).is_empty()
+}
@@ -0,0 +1,96 @@
+use crate::parse_error::{self, InputLine, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct Stack {
+    pub order: u8,
+    pub crates: Vec<char>,
+}
+
+#[derive(Debug)]
+pub struct Move {
+    pub crates_to_move: u8,
+    pub from: u8,
+    pub to: u8,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub stacks: Vec<Stack>,
+    pub moves: Vec<Move>,
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let lines_array = parse_error::lines(input).collect::<Vec<_>>();
+
+    let splitted_lines = lines_array
+        .split(|line| line.text.is_empty())
+        .collect::<Vec<_>>();
+
+    let stack_configuration_lines = splitted_lines[0];
+    if stack_configuration_lines.is_empty() {
+        return Err(InputLine::new(0, "").missing("expected the stacks of crates"));
+    }
+
+    let stacks = extract_stacks(stack_configuration_lines);
+
+    let moves_lines = match splitted_lines.get(1) {
+        Some(moves_lines) => moves_lines,
+        None => {
+            let last_line = stack_configuration_lines.last().unwrap();
+            return Err(last_line.missing("expected an empty line followed by the moves"));
+        }
+    };
+    let moves = extract_moves(moves_lines)?;
+
+    Ok(Input { stacks, moves })
+}
+
+fn extract_stacks(stack_configuration_lines: &[InputLine]) -> Vec<Stack> {
+    let first_line = stack_configuration_lines[0].text;
+    let number_of_stacks = (first_line.len() + 1) / 4;
+
+    let number_of_lines_for_stack_configuration = stack_configuration_lines.len();
+
+    (1..number_of_stacks + 1)
+        .map(|i| {
+            let crates = (0..number_of_lines_for_stack_configuration - 1)
+                .rev()
+                .map(|line| {
+                    let line = stack_configuration_lines[line].text;
+                    let index = (i - 1) * 4 + 1;
+                    // trailing spaces may have been trimmed, leaving no crate at the end of the line
+                    line.chars().nth(index).unwrap_or(' ')
+                })
+                .filter(|crate_name| crate_name != &' ')
+                .collect::<Vec<_>>();
+
+            Stack {
+                order: i as u8,
+                crates,
+            }
+        })
+        .collect()
+}
+
+fn extract_moves(moves_lines: &[InputLine]) -> Result<Vec<Move>, ParseError> {
+    let regex_string = r"^move (\d+) from (\d+) to (\d+)$";
+    let regex = Regex::new(regex_string).unwrap();
+
+    moves_lines
+        .iter()
+        .map(|line| {
+            let values = line.captures(&regex)?;
+
+            let crates_to_move = line.parse::<u8>(&values[1])?;
+            let from = line.parse::<u8>(&values[2])?;
+            let to = line.parse::<u8>(&values[3])?;
+
+            Ok(Move {
+                crates_to_move,
+                from,
+                to,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,35 @@
+use super::input::Input;
+
+pub type PuzzleResult = String;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let mut stacks = input.stacks.clone();
+
+    for move_ in input.moves.iter() {
+        let from_stack_index = stacks
+            .iter()
+            .position(|stack| stack.order == move_.from)
+            .unwrap();
+
+        let to_stack_index = stacks
+            .iter()
+            .position(|stack| stack.order == move_.to)
+            .unwrap();
+
+        let from_stack = stacks.get_mut(from_stack_index).unwrap();
+
+        let crates_to_move = from_stack
+            .crates
+            .split_off(from_stack.crates.len() - move_.crates_to_move as usize);
+
+        let to_stack = stacks.get_mut(to_stack_index).unwrap();
+
+        to_stack.crates.extend(crates_to_move);
+    }
+
+    stacks
+        .iter()
+        .map(|stack| stack.crates.last().unwrap().to_string())
+        .collect::<Vec<_>>()
+        .join("")
+}
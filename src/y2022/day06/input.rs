@@ -0,0 +1,7 @@
+use crate::parse_error::ParseError;
+
+pub type Input = Vec<char>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    Ok(input.trim().chars().collect())
+}
@@ -0,0 +1,22 @@
+use super::input::Input;
+use itertools::Itertools;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let mut index = 0;
+
+    const CHARS_PER_PACKET: u8 = 4;
+
+    loop {
+        let mut chars = input.iter().skip(index).take(CHARS_PER_PACKET as usize);
+
+        if chars.all_unique() {
+            break;
+        }
+
+        index += 1;
+    }
+
+    index + CHARS_PER_PACKET as usize
+}
@@ -0,0 +1,81 @@
+use crate::parse_error::{self, ParseError};
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+pub struct File {
+    pub size: u32,
+}
+
+pub type Directories = BTreeMap<String, Vec<File>>;
+pub type Input = Directories;
+
+const ROOT_DIRECTORY_NAME: &str = "/";
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut directories = Directories::new();
+
+    let mut current_dir_name = String::from("");
+
+    directories.insert(String::from(ROOT_DIRECTORY_NAME), vec![]);
+
+    for line in parse_error::lines(input) {
+        let args = line.text.split_whitespace().collect::<Vec<&str>>();
+        if args.len() < 2 {
+            return Err(line.missing("expected a command or a directory entry"));
+        }
+
+        let is_command = args[0] == "$";
+
+        match is_command {
+            true => {
+                let command_name = args[1];
+
+                if command_name == "cd" {
+                    if args.len() < 3 {
+                        return Err(line.missing("expected the name of a directory"));
+                    }
+
+                    if args[2] == "/" {
+                        current_dir_name = String::from(ROOT_DIRECTORY_NAME);
+                    } else if args[2] == ".." {
+                        current_dir_name = current_dir_name
+                            .split('/')
+                            .take(current_dir_name.split('/').count() - 1)
+                            .collect::<Vec<&str>>()
+                            .join("/");
+
+                        if current_dir_name.is_empty() {
+                            current_dir_name = String::from(ROOT_DIRECTORY_NAME);
+                        }
+                    } else if current_dir_name.ends_with('/') {
+                        current_dir_name += args[2];
+                    } else {
+                        current_dir_name += format!("/{}", args[2]).as_str();
+                    }
+                }
+            }
+            false => {
+                if args[0] == "dir" {
+                    let name = args[1].to_string();
+
+                    let dir_name = if current_dir_name == "/" {
+                        current_dir_name.clone() + &name
+                    } else {
+                        current_dir_name.clone() + "/" + &name
+                    };
+
+                    directories.insert(dir_name, vec![]);
+                } else {
+                    let size = line.parse::<u32>(args[0])?;
+
+                    directories
+                        .entry(current_dir_name.clone())
+                        .or_default()
+                        .push(File { size });
+                }
+            }
+        }
+    }
+
+    Ok(directories)
+}
@@ -0,0 +1,36 @@
+use super::input::Input;
+use std::collections::BTreeMap;
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(directories: &Input) -> PuzzleResult {
+    let directories_total_sizes = directories.iter().map(|(dir_name, files)| {
+        let total_size = files.iter().map(|file| file.size).sum::<u32>();
+
+        (dir_name, total_size)
+    });
+    let directories_total_sizes = BTreeMap::from_iter(directories_total_sizes);
+
+    let directories_with_nested_directories_total_sizes = directories_total_sizes
+        .keys()
+        .map(|dir_name| {
+            let total_size = directories_total_sizes
+                .iter()
+                .filter(|(nested_dir_name, _)| nested_dir_name.starts_with(*dir_name))
+                .map(|(_, nested_dir_total_size)| nested_dir_total_size)
+                .sum::<u32>();
+
+            (dir_name, total_size)
+        })
+        .collect::<Vec<_>>();
+
+    let directories_with_at_most_100000 = directories_with_nested_directories_total_sizes
+        .iter()
+        .filter(|(_, total_size)| *total_size <= 100000)
+        .collect::<Vec<_>>();
+
+    directories_with_at_most_100000
+        .iter()
+        .map(|(_, total_size)| total_size)
+        .sum::<PuzzleResult>()
+}
@@ -0,0 +1,54 @@
+use super::input::Input;
+use std::collections::BTreeMap;
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(directories: &Input) -> PuzzleResult {
+    let directories_total_sizes = directories.iter().map(|(dir_name, files)| {
+        let total_size = files.iter().map(|file| file.size).sum::<u32>();
+
+        (dir_name, total_size)
+    });
+    let directories_total_sizes = BTreeMap::from_iter(directories_total_sizes);
+
+    let directories_with_nested_dirs_total_sizes = directories_total_sizes
+        .keys()
+        .map(|dir_name| {
+            let total_size = directories_total_sizes
+                .iter()
+                .filter(|(nested_dir_name, _)| nested_dir_name.starts_with(dir_name.as_str()))
+                .map(|(_, nested_dir_total_size)| nested_dir_total_size)
+                .sum::<u32>();
+
+            (dir_name, total_size)
+        })
+        .collect::<Vec<_>>();
+
+    let total_used_space = directories_with_nested_dirs_total_sizes
+        .iter()
+        .find(|(dir_name, _)| **dir_name == "/")
+        .unwrap()
+        .1;
+
+    let mut directories_with_one_dir_deleted =
+        directories_with_nested_dirs_total_sizes
+            .iter()
+            .map(|(dir_name, total_size)| {
+                let total_size_after_deletion = total_used_space - total_size;
+
+                (dir_name, total_size, total_size_after_deletion)
+            });
+
+    const TOTAL_DISK_SPACE: u32 = 70000000;
+    const TOTAL_SPACE_TO_UPDATE: u32 = 30000000;
+
+    const FREE_SPACE_REQUIRED: u32 = TOTAL_DISK_SPACE - TOTAL_SPACE_TO_UPDATE;
+
+    let smaller_dir_to_delete = directories_with_one_dir_deleted
+        .rfind(|(_, _, total_size_after_deletion)| {
+            *total_size_after_deletion <= FREE_SPACE_REQUIRED
+        })
+        .unwrap();
+
+    *smaller_dir_to_delete.1
+}
@@ -0,0 +1,10 @@
+use crate::{grid::Grid, parse_error::ParseError};
+
+pub type Height = u8;
+pub type Input = Grid<Height>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    Grid::parse(input, "expected the height of a tree (a digit)", |char| {
+        char.to_digit(10).map(|height| height as Height)
+    })
+}
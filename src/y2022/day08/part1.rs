@@ -0,0 +1,23 @@
+use super::input::{Height, Input};
+use crate::geometry::Point2;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(trees: &Input) -> PuzzleResult {
+    trees
+        .iter()
+        .filter(|(Point2 { x, y }, height)| {
+            let is_lower = |other_height: &Height| other_height < height;
+
+            let is_visible_from_left = trees.row(*y).unwrap().take(*x).all(is_lower);
+            let is_visible_from_right = trees.row(*y).unwrap().skip(x + 1).all(is_lower);
+            let is_visible_from_top = trees.column(*x).unwrap().take(*y).all(is_lower);
+            let is_visible_from_bottom = trees.column(*x).unwrap().skip(y + 1).all(is_lower);
+
+            is_visible_from_left
+                || is_visible_from_right
+                || is_visible_from_top
+                || is_visible_from_bottom
+        })
+        .count()
+}
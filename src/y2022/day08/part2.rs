@@ -0,0 +1,43 @@
+use super::input::{Height, Input};
+use crate::geometry::Point2;
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(trees: &Input) -> PuzzleResult {
+    trees
+        .iter()
+        .map(|(Point2 { x, y }, tree_height)| {
+            let left_trees = trees.row(y).unwrap().rev().skip(trees.width() - x);
+            let viewing_distance_left = get_viewing_distance(*tree_height, left_trees);
+
+            let right_trees = trees.row(y).unwrap().skip(x + 1);
+            let viewing_distance_right = get_viewing_distance(*tree_height, right_trees);
+
+            let top_trees = trees.column(x).unwrap().rev().skip(trees.height() - y);
+            let viewing_distance_top = get_viewing_distance(*tree_height, top_trees);
+
+            let bottom_trees = trees.column(x).unwrap().skip(y + 1);
+            let viewing_distance_bottom = get_viewing_distance(*tree_height, bottom_trees);
+
+            viewing_distance_left
+                * viewing_distance_right
+                * viewing_distance_top
+                * viewing_distance_bottom
+        })
+        .max()
+        .unwrap()
+}
+
+fn get_viewing_distance<'a>(height: Height, next_trees: impl Iterator<Item = &'a Height>) -> u32 {
+    let mut number_of_trees_visible = 0;
+
+    for next_height in next_trees {
+        number_of_trees_visible += 1;
+
+        if *next_height >= height {
+            break;
+        }
+    }
+
+    number_of_trees_visible
+}
@@ -0,0 +1,42 @@
+use crate::{
+    geometry::Direction4,
+    parse_error::{self, ParseError},
+};
+
+#[derive(Debug)]
+pub struct Move {
+    pub direction: Direction4,
+    pub distance: u8,
+}
+
+pub type Input = Vec<Move>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut moves = Vec::new();
+
+    for line in parse_error::lines(input) {
+        let (direction, distance) = line
+            .text
+            .split_once(' ')
+            .ok_or_else(|| line.missing("expected a direction followed by a distance"))?;
+
+        let direction = match direction {
+            "U" => Direction4::Up,
+            "D" => Direction4::Down,
+            "L" => Direction4::Left,
+            "R" => Direction4::Right,
+            _ => return Err(line.error(direction, "expected U, D, L or R")),
+        };
+
+        let distance = line.parse::<u8>(distance)?;
+
+        let _move = Move {
+            direction,
+            distance,
+        };
+
+        moves.push(_move);
+    }
+
+    Ok(moves)
+}
@@ -0,0 +1,39 @@
+use super::input::Input;
+use crate::geometry::Point2;
+use std::collections::HashSet;
+
+type Position = Point2<i32>;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(moves: &Input) -> PuzzleResult {
+    let mut grid_visits: HashSet<Position> = HashSet::new();
+
+    let mut head_position = Position::ZERO;
+    let mut tail_position = Position::ZERO;
+
+    grid_visits.insert(tail_position);
+
+    for _move in moves {
+        for _ in 0.._move.distance {
+            head_position += _move.direction.offset();
+
+            tail_position = move_tail(tail_position, head_position);
+
+            grid_visits.insert(tail_position);
+        }
+    }
+
+    grid_visits.len()
+}
+
+fn move_tail(tail_position: Position, head_position: Position) -> Position {
+    let difference = head_position - tail_position;
+
+    let is_touching = difference.x.abs() <= 1 && difference.y.abs() <= 1;
+    if is_touching {
+        return tail_position;
+    }
+
+    tail_position + Point2::new(difference.x.signum(), difference.y.signum())
+}
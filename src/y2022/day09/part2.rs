@@ -0,0 +1,42 @@
+use super::input::Input;
+use crate::geometry::Point2;
+use std::collections::HashSet;
+
+type Position = Point2<i32>;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(moves: &Input) -> PuzzleResult {
+    const ROPE_LENGTH: usize = 10;
+
+    let mut rope = [Position::ZERO; ROPE_LENGTH];
+
+    let mut grid_visits: HashSet<Position> = HashSet::new();
+    grid_visits.insert(Position::ZERO);
+
+    for _move in moves {
+        for _ in 0.._move.distance {
+            rope[0] += _move.direction.offset();
+
+            for index in 1..ROPE_LENGTH {
+                rope[index] = move_tail(rope[index], rope[index - 1]);
+            }
+
+            const TAIL_INDEX: usize = ROPE_LENGTH - 1;
+            grid_visits.insert(rope[TAIL_INDEX]);
+        }
+    }
+
+    grid_visits.len()
+}
+
+fn move_tail(tail_position: Position, head_position: Position) -> Position {
+    let difference = head_position - tail_position;
+
+    let is_touching = difference.x.abs() <= 1 && difference.y.abs() <= 1;
+    if is_touching {
+        return tail_position;
+    }
+
+    tail_position + Point2::new(difference.x.signum(), difference.y.signum())
+}
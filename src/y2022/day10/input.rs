@@ -0,0 +1,26 @@
+use crate::parse_error::{self, InputLine, ParseError};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    Addx(i8),
+}
+
+pub type Input = Vec<Instruction>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    parse_error::lines(input).map(read_instruction).collect()
+}
+
+fn read_instruction(line: InputLine) -> Result<Instruction, ParseError> {
+    if line.text == "noop" {
+        return Ok(Instruction::Noop);
+    }
+
+    if let Some(x) = line.text.strip_prefix("addx ") {
+        let x = line.parse::<i8>(x)?;
+        return Ok(Instruction::Addx(x));
+    }
+
+    Err(line.error_at(0, "expected noop or addx"))
+}
@@ -1,8 +1,4 @@
-#[derive(Debug)]
-enum Instruction {
-    Noop,
-    Addx(i8),
-}
+use super::input::{Input, Instruction};
 
 #[derive(Debug)]
 struct SignalStrength {
@@ -10,9 +6,9 @@ struct SignalStrength {
     x: i32,
 }
 
-pub fn run(input: &str) {
-    let lines = input.lines();
+pub type PuzzleResult = i32;
 
+pub fn get_result(instructions: &Input) -> PuzzleResult {
     const INITIAL_X: i32 = 1;
     let mut x = INITIAL_X;
 
@@ -22,10 +18,8 @@ pub fn run(input: &str) {
 
     const CYCLES_TO_CHECK: [i32; 6] = [20, 60, 100, 140, 180, 220];
 
-    for line in lines {
-        let instruction = read_instruction(line);
-
-        match instruction {
+    for instruction in instructions {
+        match *instruction {
             Instruction::Noop => {
                 cycle += 1;
 
@@ -52,25 +46,8 @@ pub fn run(input: &str) {
         }
     }
 
-    let total = signal_strengths
+    signal_strengths
         .iter()
         .map(|signal_strength| signal_strength.cycle * signal_strength.x)
-        .sum::<i32>();
-
-    println!("{:?}", total);
-}
-
-fn read_instruction(line: &str) -> Instruction {
-    if line == "noop" {
-        return Instruction::Noop;
-    }
-
-    let args = line.split_whitespace().collect::<Vec<_>>();
-
-    if args[0] == "addx" {
-        let x = args[1].parse::<i8>().unwrap();
-        return Instruction::Addx(x);
-    }
-
-    panic!("Invalid instruction: {}", line);
+        .sum::<PuzzleResult>()
 }
@@ -1,11 +1,6 @@
+use super::input::{Input, Instruction};
 use itertools::Itertools;
 
-#[derive(Debug)]
-enum Instruction {
-    Noop,
-    Addx(i8),
-}
-
 #[derive(Debug, Copy, Clone)]
 enum Pixel {
     Lit,
@@ -20,9 +15,9 @@ struct CrtRow {
 const CRT_WIDTH: u8 = 40;
 const CRT_HEIGHT: u8 = 6;
 
-pub fn run(input: &str) {
-    let lines = input.lines();
+pub type PuzzleResult = String;
 
+pub fn get_result(instructions: &Input) -> PuzzleResult {
     const INITIAL_X: i32 = 1;
     let mut x = INITIAL_X;
 
@@ -30,10 +25,8 @@ pub fn run(input: &str) {
 
     let mut pixels = Vec::new();
 
-    for line in lines {
-        let instruction = read_instruction(line);
-
-        match instruction {
+    for instruction in instructions {
+        match *instruction {
             Instruction::Noop => {
                 draw_pixel(cycle, x, &mut pixels);
 
@@ -63,22 +56,7 @@ pub fn run(input: &str) {
         })
         .collect::<Vec<_>>();
 
-    display_crt(crt_rows);
-}
-
-fn read_instruction(line: &str) -> Instruction {
-    if line == "noop" {
-        return Instruction::Noop;
-    }
-
-    let args = line.split_whitespace().collect::<Vec<_>>();
-
-    if args[0] == "addx" {
-        let x = args[1].parse::<i8>().unwrap();
-        return Instruction::Addx(x);
-    }
-
-    panic!("Invalid instruction: {}", line);
+    display_crt(crt_rows)
 }
 
 fn is_pixel_drawn(cycle: i32, x: i32) -> bool {
@@ -96,15 +74,19 @@ fn draw_pixel(cycle: i32, x: i32, pixels: &mut Vec<Pixel>) {
     pixels.push(pixel);
 }
 
-fn display_crt(crt_rows: Vec<CrtRow>) {
-    for crt_row in crt_rows {
-        for pixel in crt_row.pixels {
-            match pixel {
-                Pixel::Lit => print!("#"),
-                Pixel::Dark => print!("."),
-            }
-        }
-
-        println!();
-    }
+fn display_crt(crt_rows: Vec<CrtRow>) -> String {
+    crt_rows
+        .iter()
+        .map(|crt_row| {
+            crt_row
+                .pixels
+                .iter()
+                .map(|pixel| match pixel {
+                    Pixel::Lit => '#',
+                    Pixel::Dark => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
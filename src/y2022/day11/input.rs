@@ -0,0 +1,161 @@
+use crate::parse_error::{self, InputLine, ParseError};
+use regex::Regex;
+
+pub type Monkey = u8;
+pub type WorryLevel = u64;
+
+#[derive(Debug)]
+pub struct InstructionTest {
+    pub divisible_by: u8,
+    pub monkey_receiver_when_true: Monkey,
+    pub monkey_receiver_when_false: Monkey,
+}
+
+#[derive(Debug)]
+pub enum Operator {
+    Addition,
+    Subtraction,
+    Multiplication,
+    Division,
+}
+
+#[derive(Debug)]
+pub enum Operand {
+    Value(u8),
+    OldValue,
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    pub operator: Operator,
+    pub left: Operand,
+    pub right: Operand,
+}
+
+#[derive(Debug)]
+pub struct MonkeyInstruction {
+    pub monkey: Monkey,
+    pub starting_items: Vec<WorryLevel>,
+    pub operation: Operation,
+    pub test: InstructionTest,
+}
+
+pub type Input = Vec<MonkeyInstruction>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let lines_array = parse_error::lines(input).collect::<Vec<_>>();
+
+    let splitted_lines = lines_array
+        .split(|line| line.text.is_empty())
+        .collect::<Vec<&[InputLine]>>();
+
+    splitted_lines
+        .into_iter()
+        .filter(|lines| !lines.is_empty())
+        .map(parse_instruction)
+        .collect::<Result<Vec<_>, _>>()
+}
+
+fn parse_instruction(lines: &[InputLine]) -> Result<MonkeyInstruction, ParseError> {
+    if lines.len() < 6 {
+        let last_line = lines[lines.len() - 1];
+        return Err(last_line.missing("expected 6 lines to describe a monkey"));
+    }
+
+    let monkey_line = lines[0];
+    let starting_items_line = lines[1];
+    let operation_line = lines[2];
+    let test_line = lines[3];
+    let test_line_when_true = lines[4];
+    let test_line_when_false = lines[5];
+
+    let monkey = parse_monkey(monkey_line)?;
+    let starting_items = parse_starting_items(starting_items_line)?;
+    let operation = parse_operation(operation_line)?;
+    let test = parse_test(test_line, test_line_when_true, test_line_when_false)?;
+
+    Ok(MonkeyInstruction {
+        monkey,
+        starting_items,
+        operation,
+        test,
+    })
+}
+
+fn parse_monkey(line: InputLine) -> Result<Monkey, ParseError> {
+    let regex = Regex::new(r"Monkey (\d+):").unwrap();
+    let captures = line.captures(&regex)?;
+    line.parse(&captures[1])
+}
+
+fn parse_starting_items(line: InputLine) -> Result<Vec<WorryLevel>, ParseError> {
+    let regex = Regex::new(r"Starting items: (.*)").unwrap();
+    let captures = line.captures(&regex)?;
+    let starting_items_str = &captures[1];
+
+    starting_items_str
+        .split(", ")
+        .map(|s| line.parse(s))
+        .collect::<Result<Vec<WorryLevel>, _>>()
+}
+
+fn parse_operation(operation_line: InputLine) -> Result<Operation, ParseError> {
+    let regex = Regex::new(r"Operation: new = (.+) (.) (.+)").unwrap();
+    let captures = operation_line.captures(&regex)?;
+    let left = &captures[1];
+    let operator = &captures[2];
+    let right = &captures[3];
+
+    let left = parse_operand(operation_line, left)?;
+    let operator = parse_operator(operation_line, operator)?;
+    let right = parse_operand(operation_line, right)?;
+
+    Ok(Operation {
+        operator,
+        left,
+        right,
+    })
+}
+
+fn parse_operand(line: InputLine, str: &str) -> Result<Operand, ParseError> {
+    if str == "old" {
+        return Ok(Operand::OldValue);
+    }
+
+    let value = line.parse(str)?;
+    Ok(Operand::Value(value))
+}
+
+fn parse_operator(line: InputLine, operator: &str) -> Result<Operator, ParseError> {
+    match operator {
+        "+" => Ok(Operator::Addition),
+        "-" => Ok(Operator::Subtraction),
+        "*" => Ok(Operator::Multiplication),
+        "/" => Ok(Operator::Division),
+        _ => Err(line.error(operator, "expected +, -, * or /")),
+    }
+}
+
+fn parse_test(
+    test_line: InputLine,
+    test_line_when_true: InputLine,
+    test_line_when_false: InputLine,
+) -> Result<InstructionTest, ParseError> {
+    let regex = Regex::new(r"Test: divisible by (\d+)").unwrap();
+    let captures = test_line.captures(&regex)?;
+    let divisible_by = test_line.parse(&captures[1])?;
+
+    let regex = Regex::new(r"If true: throw to monkey (\d+)").unwrap();
+    let captures = test_line_when_true.captures(&regex)?;
+    let monkey_receiver_when_true = test_line_when_true.parse(&captures[1])?;
+
+    let regex = Regex::new(r"If false: throw to monkey (\d+)").unwrap();
+    let captures = test_line_when_false.captures(&regex)?;
+    let monkey_receiver_when_false = test_line_when_false.parse(&captures[1])?;
+
+    Ok(InstructionTest {
+        divisible_by,
+        monkey_receiver_when_true,
+        monkey_receiver_when_false,
+    })
+}
@@ -0,0 +1,96 @@
+use super::input::{Input, Monkey, Operand, Operation, Operator, WorryLevel};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+type ItemIndex = u8;
+
+const ROUNDS: u16 = 10000;
+
+pub type PuzzleResult = u64;
+
+pub fn get_result(instructions: &Input) -> PuzzleResult {
+    let mut item_worries: HashMap<ItemIndex, WorryLevel> = HashMap::new();
+    let mut monkey_holding_items: HashMap<ItemIndex, Monkey> = HashMap::new();
+    let mut item_inspections: HashMap<Monkey, PuzzleResult> = HashMap::new();
+
+    {
+        let mut item_index = 0;
+
+        for instruction in instructions {
+            for starting_item in &instruction.starting_items {
+                item_worries.insert(item_index, *starting_item);
+                monkey_holding_items.insert(item_index, instruction.monkey);
+
+                item_index += 1;
+            }
+        }
+    }
+
+    let maximum_worry_level = instructions
+        .iter()
+        .map(|i| i.test.divisible_by as WorryLevel)
+        .product::<WorryLevel>();
+
+    for _ in 0..ROUNDS {
+        for instruction in instructions {
+            let currently_holding_items = monkey_holding_items
+                .iter()
+                .filter(|(_, &monkey)| monkey == instruction.monkey)
+                .map(|(&item_index, _)| item_index)
+                .collect::<Vec<_>>();
+
+            for item_index in currently_holding_items {
+                let monkey_inspection = item_inspections.entry(instruction.monkey).or_insert(0);
+                *monkey_inspection += 1;
+
+                let old_worry_level = *item_worries.get(&item_index).unwrap();
+                let new_worry_level =
+                    calculate_worry_level(old_worry_level, &instruction.operation);
+                let new_worry_level = new_worry_level % maximum_worry_level;
+
+                item_worries
+                    .entry(item_index)
+                    .and_modify(|v| *v = new_worry_level);
+
+                let test_result =
+                    new_worry_level.is_multiple_of(instruction.test.divisible_by as WorryLevel);
+                let monkey_receiver = if test_result {
+                    instruction.test.monkey_receiver_when_true
+                } else {
+                    instruction.test.monkey_receiver_when_false
+                };
+
+                monkey_holding_items
+                    .entry(item_index)
+                    .and_modify(|v| *v = monkey_receiver);
+            }
+        }
+    }
+
+    let two_most_inspections = item_inspections
+        .values()
+        .sorted_by(|a, b| b.cmp(a))
+        .take(2)
+        .collect::<Vec<_>>();
+
+    two_most_inspections[0] * two_most_inspections[1]
+}
+
+fn calculate_worry_level(old_worry_level: WorryLevel, operation: &Operation) -> WorryLevel {
+    let left_value = match operation.left {
+        Operand::OldValue => old_worry_level,
+        Operand::Value(value) => value as WorryLevel,
+    };
+
+    let right_value = match operation.right {
+        Operand::OldValue => old_worry_level,
+        Operand::Value(value) => value as WorryLevel,
+    };
+
+    match operation.operator {
+        Operator::Addition => left_value + right_value,
+        Operator::Subtraction => left_value - right_value,
+        Operator::Multiplication => left_value * right_value,
+        Operator::Division => left_value / right_value,
+    }
+}
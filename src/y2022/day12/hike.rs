@@ -0,0 +1,26 @@
+use super::input::{Heightmap, Position};
+use crate::pathfinding::Graph;
+
+/// Heightmap walked one step at a time, climbing at most one elevation higher
+pub struct Hike<'a> {
+    pub heightmap: &'a Heightmap,
+}
+
+impl Graph for Hike<'_> {
+    type Node = Position;
+
+    fn neighbours(&self, position: &Position) -> Vec<Position> {
+        find_neighbors(*position, self.heightmap)
+    }
+}
+
+fn is_accessible(position: Position, neighbor: Position, heightmap: &Heightmap) -> bool {
+    heightmap[neighbor] <= heightmap[position] + 1
+}
+
+fn find_neighbors(position: Position, heightmap: &Heightmap) -> Vec<Position> {
+    heightmap
+        .neighbours(position)
+        .filter(|neighbor| is_accessible(position, *neighbor, heightmap))
+        .collect::<Vec<_>>()
+}
@@ -0,0 +1,48 @@
+use crate::{
+    grid::{self, Grid},
+    parse_error::ParseError,
+};
+
+pub type Position = grid::Position;
+pub type Elevation = u8;
+pub type Heightmap = Grid<Elevation>;
+
+#[derive(Debug)]
+pub struct Input {
+    pub heightmap: Heightmap,
+    pub origin_position: Position,
+    pub target_position: Position,
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let map = Grid::parse(input, "expected S, E or an elevation from a to z", |char| {
+        matches!(char, 'S' | 'E' | 'a'..='z').then_some(char)
+    })?;
+
+    let find_position = |marker: char| {
+        map.iter()
+            .find(|(_, char)| **char == marker)
+            .map(|(position, _)| position)
+            .unwrap_or_default()
+    };
+
+    let origin_position = find_position('S');
+    let target_position = find_position('E');
+
+    let heightmap = map.map(|char| match char {
+        'S' => 0,
+        'E' => 25,
+        char => {
+            let ascii_value = *char as u32;
+            const START_ASCII_INDEX: u32 = 97;
+
+            (ascii_value - START_ASCII_INDEX) as u8
+        }
+    });
+
+    Ok(Input {
+        heightmap,
+        origin_position,
+        target_position,
+    })
+}
@@ -0,0 +1,29 @@
+use super::{
+    hike::Hike,
+    input::{Input, Position},
+};
+use crate::pathfinding::{self, Cost};
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let hike = Hike {
+        heightmap: &input.heightmap,
+    };
+    let target = input.target_position;
+
+    let search = pathfinding::astar(
+        &hike,
+        [input.origin_position],
+        |position| *position == target,
+        |position| calculate_distance(*position, target),
+    );
+
+    let best_path = search.path(&target).unwrap();
+
+    best_path.len() - 1
+}
+
+fn calculate_distance(origin: Position, target: Position) -> Cost {
+    origin.manhattan_distance(target) as Cost
+}
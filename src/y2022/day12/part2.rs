@@ -0,0 +1,21 @@
+use super::{hike::Hike, input::Input};
+use crate::pathfinding;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let heightmap = &input.heightmap;
+    let target_position = input.target_position;
+
+    let starting_positions = heightmap
+        .iter()
+        .filter(|(_, elevation)| **elevation == 0)
+        .map(|(position, _)| position);
+
+    // a single search from every starting position at once, each step costing the same
+    let search = pathfinding::bfs(&Hike { heightmap }, starting_positions, |position| {
+        *position == target_position
+    });
+
+    search.target_distance().unwrap() as PuzzleResult
+}
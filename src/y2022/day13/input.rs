@@ -0,0 +1,91 @@
+use crate::parse_error::{self, InputLine, ParseError};
+
+pub type Integer = u32;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Packet {
+    List(Vec<Packet>),
+    Value(Integer),
+}
+
+#[derive(Debug)]
+pub struct Pair {
+    pub left: Packet,
+    pub right: Packet,
+}
+
+pub type Input = Vec<Pair>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let lines_array = parse_error::lines(input).collect::<Vec<_>>();
+
+    let splitted_lines = lines_array
+        .split(|line| line.text.is_empty())
+        .filter(|lines| !lines.is_empty())
+        .collect::<Vec<_>>();
+
+    splitted_lines
+        .iter()
+        .map(|lines| {
+            if lines.len() < 2 {
+                return Err(lines[0].missing("expected a pair of packets"));
+            }
+
+            let left = parse_packet(lines[0], lines[0].text)?;
+            let right = parse_packet(lines[1], lines[1].text)?;
+
+            Ok(Pair { left, right })
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+/// Parse a packet, which must be a slice of the line text
+fn parse_packet(line: InputLine, str: &str) -> Result<Packet, ParseError> {
+    if !str.starts_with('[') || !str.ends_with(']') {
+        return Err(line.error(str, "expected a list between brackets"));
+    }
+
+    let start_index = 1;
+    let end_index = str.len() - 1;
+
+    let mut packet = Vec::new();
+
+    let mut current_start_index = start_index;
+
+    let mut nested_level = 0;
+
+    for index in start_index..=end_index {
+        let next_char = str.as_bytes()[index];
+        let is_last_index = index == end_index;
+
+        if (next_char == b',' || is_last_index) && nested_level == 0 {
+            let current_str = &str[current_start_index..index];
+            current_start_index = index + 1;
+
+            if current_str.is_empty() {
+                continue;
+            }
+
+            let has_nested_packet = current_str.contains('[');
+
+            let packet_value = match has_nested_packet {
+                true => parse_packet(line, current_str)?,
+                false => {
+                    let value = line.parse::<Integer>(current_str)?;
+                    Packet::Value(value)
+                }
+            };
+
+            packet.push(packet_value);
+            continue;
+        }
+        if next_char == b'[' {
+            nested_level += 1;
+        }
+        if next_char == b']' {
+            nested_level -= 1;
+        }
+    }
+
+    Ok(Packet::List(packet))
+}
@@ -0,0 +1,70 @@
+use super::input::{Input, Packet};
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(pairs: &Input) -> PuzzleResult {
+    let right_orders = pairs
+        .iter()
+        .enumerate()
+        .map(|(index, pair)| {
+            let is_in_right_order = compare_values(&pair.left, &pair.right) == Some(true);
+            if is_in_right_order {
+                return (index + 1) as u32;
+            }
+
+            0
+        })
+        .collect::<Vec<_>>();
+
+    right_orders.into_iter().sum::<PuzzleResult>()
+}
+
+fn compare_values(left_value: &Packet, right_value: &Packet) -> Option<bool> {
+    match (left_value, right_value) {
+        (Packet::Value(left_value), Packet::Value(right_value)) => {
+            if left_value == right_value {
+                return None;
+            }
+
+            Some(left_value < right_value)
+        }
+        (Packet::List(left_list), Packet::List(right_list)) => {
+            let left_max_index = left_list.len();
+            let right_max_index = right_list.len();
+
+            let max_index = [left_max_index, right_max_index].into_iter().max().unwrap();
+
+            for index in 0..max_index {
+                let left_value = left_list.get(index);
+                let right_value = right_list.get(index);
+
+                let is_right_order = match (left_value, right_value) {
+                    (None, None) => None,
+                    (None, _) => Some(true),
+                    (_, None) => Some(false),
+                    (Some(left_value), Some(right_value)) => {
+                        compare_values(left_value, right_value)
+                    }
+                };
+
+                if is_right_order.is_some() {
+                    return is_right_order;
+                }
+            }
+
+            None
+        }
+        (left_list, Packet::Value(right_value)) => {
+            let packet = vec![Packet::Value(*right_value)];
+            let right_list = Packet::List(packet);
+
+            compare_values(left_list, &right_list)
+        }
+        (Packet::Value(left_value), right_list) => {
+            let packet = vec![Packet::Value(*left_value)];
+            let left_list = Packet::List(packet);
+
+            compare_values(&left_list, right_list)
+        }
+    }
+}
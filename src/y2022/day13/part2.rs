@@ -1,28 +1,12 @@
+use super::input::{Input, Packet};
 use itertools::Itertools;
 
-type Integer = u32;
+pub type PuzzleResult = usize;
 
-#[derive(Debug, Clone, PartialEq)]
-enum Packet {
-    List(Vec<Packet>),
-    Value(Integer),
-}
-
-pub fn run(input: &str) {
-    let lines = input.lines();
-
-    let lines_array = lines.collect::<Vec<_>>();
-
-    let splitted_lines = lines_array.split(|&s| s.is_empty()).collect::<Vec<_>>();
-
-    let input_packets = splitted_lines
-        .into_iter()
-        .flat_map(|lines| {
-            let left = parse_packet(lines[0]);
-            let right = parse_packet(lines[1]);
-
-            vec![left, right]
-        })
+pub fn get_result(pairs: &Input) -> PuzzleResult {
+    let input_packets = pairs
+        .iter()
+        .flat_map(|pair| vec![pair.left.clone(), pair.right.clone()])
         .collect::<Vec<_>>();
 
     let packet_with_two = Packet::List(vec![Packet::Value(2)]);
@@ -44,66 +28,15 @@ pub fn run(input: &str) {
 
     let index_of_packet_with_two = sorted_packets
         .iter()
-        .position(|packet| packet.clone() == packet_with_two)
+        .position(|packet| *packet == packet_with_two)
         .unwrap();
 
     let index_of_packet_with_six = sorted_packets
         .iter()
-        .position(|packet| packet.clone() == packet_with_six)
+        .position(|packet| *packet == packet_with_six)
         .unwrap();
 
-    let decoder_key = (index_of_packet_with_two + 1) * (index_of_packet_with_six + 1);
-
-    println!("{:?}", decoder_key);
-}
-
-fn parse_packet(str: &str) -> Packet {
-    let start_index = 1;
-    let end_index = str.len() - 1;
-
-    let mut packet = Vec::new();
-
-    let mut current_str = String::new();
-
-    let mut nested_level = 0;
-
-    for index in start_index..=end_index {
-        let next_char = str.get(index..index + 1).unwrap();
-        let is_last_index = index == end_index;
-
-        if next_char == "," || is_last_index {
-            if nested_level == 0 {
-                if current_str.is_empty() {
-                    continue;
-                }
-
-                let has_nested_packet = current_str.contains("[");
-
-                let packet_value = match has_nested_packet {
-                    true => parse_packet(current_str.as_str()),
-                    false => {
-                        let value = current_str.parse::<Integer>().unwrap();
-                        Packet::Value(value)
-                    }
-                };
-
-                packet.push(packet_value);
-
-                current_str = String::new();
-                continue;
-            }
-        }
-        if next_char == "[" {
-            nested_level += 1;
-        }
-        if next_char == "]" {
-            nested_level -= 1;
-        }
-
-        current_str += next_char;
-    }
-
-    Packet::List(packet)
+    (index_of_packet_with_two + 1) * (index_of_packet_with_six + 1)
 }
 
 fn compare_values(left_value: &Packet, right_value: &Packet) -> Option<bool> {
@@ -142,13 +75,13 @@ fn compare_values(left_value: &Packet, right_value: &Packet) -> Option<bool> {
             None
         }
         (left_list, Packet::Value(right_value)) => {
-            let packet = vec![Packet::Value(right_value.clone())];
+            let packet = vec![Packet::Value(*right_value)];
             let right_list = Packet::List(packet);
 
             compare_values(left_list, &right_list)
         }
         (Packet::Value(left_value), right_list) => {
-            let packet = vec![Packet::Value(left_value.clone())];
+            let packet = vec![Packet::Value(*left_value)];
             let left_list = Packet::List(packet);
 
             compare_values(&left_list, right_list)
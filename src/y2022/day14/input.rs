@@ -0,0 +1,29 @@
+use crate::{
+    geometry::Point2,
+    parse_error::{self, ParseError},
+};
+
+pub type CoordValue = i64;
+pub type Position = Point2<CoordValue>;
+
+pub type Path = Vec<Position>;
+pub type Input = Vec<Path>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    parse_error::lines(input)
+        .map(|line| {
+            line.text
+                .split("->")
+                .map(|position| {
+                    let (x, y) = position
+                        .split_once(',')
+                        .ok_or_else(|| line.error(position, "expected a position like x,y"))?;
+                    let x = line.parse::<CoordValue>(x.trim())?;
+                    let y = line.parse::<CoordValue>(y.trim())?;
+
+                    Ok(Position::new(x, y))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
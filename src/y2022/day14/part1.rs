@@ -0,0 +1,90 @@
+use super::input::Input;
+use crate::{
+    geometry::{Direction8, Point2},
+    grid::{SparseGrid, SparsePosition},
+};
+
+#[derive(Debug, PartialEq)]
+enum Drawing {
+    Rock,
+    Sand,
+}
+
+type Map = SparseGrid<Drawing>;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(paths: &Input) -> PuzzleResult {
+    let mut map = Map::new();
+
+    for path in paths {
+        let path_lines = path.windows(2);
+
+        for path_line in path_lines {
+            let start = &path_line[0];
+            let end = &path_line[1];
+
+            let is_vertical = start.x == end.x;
+
+            if is_vertical {
+                let x = start.x;
+
+                let min_y = start.y.min(end.y);
+                let max_y = start.y.max(end.y);
+
+                for y in min_y..=max_y {
+                    map.insert(Point2::new(x, y), Drawing::Rock);
+                }
+            } else {
+                let y = start.y;
+
+                let min_x = start.x.min(end.x);
+                let max_x = start.x.max(end.x);
+
+                for x in min_x..=max_x {
+                    map.insert(Point2::new(x, y), Drawing::Rock);
+                }
+            }
+        }
+    }
+
+    let sand_start_position = Point2::new(500, 0);
+
+    let (_, max) = map.bounds().unwrap();
+    let max_y = max.y;
+
+    while let Some(next_falling_sand_position) =
+        get_next_falling_sand_position(&map, sand_start_position, max_y)
+    {
+        map.insert(next_falling_sand_position, Drawing::Sand);
+    }
+
+    map.iter()
+        .filter(|(_, drawing)| **drawing == Drawing::Sand)
+        .count()
+}
+
+/// Position where the sand comes to rest, unless it flows below the lowest rock
+fn get_next_falling_sand_position(
+    map: &Map,
+    sand_start_position: SparsePosition,
+    max_y: i64,
+) -> Option<SparsePosition> {
+    let mut position = sand_start_position;
+
+    loop {
+        if position.y >= max_y {
+            return None;
+        }
+
+        let next_position = [Direction8::Down, Direction8::DownLeft, Direction8::DownRight]
+            .into_iter()
+            .map(|direction| position + direction.offset())
+        .find(|position| !map.contains(*position));
+
+        match next_position {
+            Some(next_position) => position = next_position,
+            None => return Some(position),
+        }
+    }
+}
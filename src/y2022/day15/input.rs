@@ -0,0 +1,51 @@
+use crate::{
+    geometry::Point2,
+    parse_error::{self, ParseError},
+};
+use regex::Regex;
+
+pub type Position = Point2<i32>;
+
+#[derive(Debug)]
+pub struct Sensor {
+    pub position: Position,
+    pub closest_beacon: Position,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub sensors: Vec<Sensor>,
+    /// Row to check in the first part
+    pub y_search: i32,
+    /// Maximum coordinate of the distress beacon in the second part
+    pub search_limit: i32,
+}
+
+/// Parse the sensors, searched for the distress beacon around the given row and up to the given limit
+pub fn parse_input(input: &str, y_search: i32, search_limit: i32) -> Result<Input, ParseError> {
+    let regex = Regex::new(
+        r"^Sensor at x=([-]?\d+), y=([-]?\d+): closest beacon is at x=([-]?\d+), y=([-]?\d+)$",
+    )
+    .unwrap();
+
+    let sensors = parse_error::lines(input)
+        .map(|line| {
+            let captures = line.captures(&regex)?;
+            let x = line.parse::<i32>(&captures[1])?;
+            let y = line.parse::<i32>(&captures[2])?;
+            let closest_beacon_x = line.parse::<i32>(&captures[3])?;
+            let closest_beacon_y = line.parse::<i32>(&captures[4])?;
+
+            Ok(Sensor {
+                position: Position::new(x, y),
+                closest_beacon: Position::new(closest_beacon_x, closest_beacon_y),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Input {
+        sensors,
+        y_search,
+        search_limit,
+    })
+}
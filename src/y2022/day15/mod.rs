@@ -0,0 +1,52 @@
+pub mod input;
+pub mod part1;
+pub mod part2;
+
+use crate::{parse_error::ParseError, range_set::RangeSet, solution::Solution};
+use input::Sensor;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = input::Input;
+    type Part1 = part1::PuzzleResult;
+    type Part2 = part2::PuzzleResult;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_input(input, 2_000_000, 4_000_000)
+    }
+
+    /// The sample covers a much smaller area than the real input
+    fn parse_sample(input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_input(input, 10, 20)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::get_result(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::get_result(input)
+    }
+}
+
+/// Positions of the row within the range of a sensor, where no unknown beacon can be
+pub fn get_covered_positions(sensors: &[Sensor], y_search: i32) -> RangeSet<i32> {
+    let mut covered_positions = RangeSet::new();
+
+    for sensor in sensors.iter() {
+        let max_distance = sensor.position.manhattan_distance(sensor.closest_beacon);
+
+        let distance_to_y = (sensor.position.y - y_search).abs();
+
+        if distance_to_y > max_distance {
+            continue;
+        }
+
+        let diff = max_distance - distance_to_y;
+
+        covered_positions.insert(sensor.position.x - diff..=sensor.position.x + diff);
+    }
+
+    covered_positions
+}
@@ -0,0 +1,24 @@
+use super::{get_covered_positions, input::Input};
+use std::collections::HashSet;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let sensors = &input.sensors;
+    let y_search = input.y_search;
+
+    let covered_positions = get_covered_positions(sensors, y_search);
+
+    let beacons_on_row = sensors
+        .iter()
+        .map(|sensor| sensor.closest_beacon)
+        .filter(|beacon| beacon.y == y_search)
+        .collect::<HashSet<_>>();
+
+    let covered_beacons = beacons_on_row
+        .iter()
+        .filter(|beacon| covered_positions.contains(beacon.x))
+        .count();
+
+    covered_positions.len() as PuzzleResult - covered_beacons
+}
@@ -0,0 +1,71 @@
+use super::{
+    get_covered_positions,
+    input::{Input, Position, Sensor},
+};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+
+pub type PuzzleResult = u128;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let sensors = &input.sensors;
+    let search_limit = input.search_limit;
+
+    let distress_beacon = get_candidate_rows(sensors, search_limit)
+        .into_iter()
+        .filter(|y| (0..=search_limit).contains(y))
+        .find_map(|y| {
+            let uncovered_positions = get_covered_positions(sensors, y).gaps(0..=search_limit);
+
+            let x = uncovered_positions.ranges().next().map(|range| *range.start());
+
+            x.map(|x| Position::new(x, y))
+        })
+        .unwrap_or_else(|| unreachable!("The distress beacon is on none of the candidate rows"));
+
+    get_tuning_frequency(distress_beacon)
+}
+
+/// Rows where the edges just outside the range of the sensors cross each other, or cross the
+/// borders of the search area
+///
+/// The distress beacon being the only position left uncovered, it is surrounded by the ranges of
+/// the sensors, so both a descending and an ascending edge go through it. On a border of the search
+/// area, it may only be next to the range of the sensors on one side, so a single edge goes through
+/// it.
+fn get_candidate_rows(sensors: &[Sensor], search_limit: i32) -> BTreeSet<i32> {
+    // descending edges follow y = x + a, ascending edges follow y = -x + b
+    let mut descending_edges = Vec::new();
+    let mut ascending_edges = Vec::new();
+
+    for sensor in sensors.iter() {
+        let distance = sensor.position.manhattan_distance(sensor.closest_beacon) + 1;
+        let Position { x, y } = sensor.position;
+
+        descending_edges.extend([y - x - distance, y - x + distance]);
+        ascending_edges.extend([y + x - distance, y + x + distance]);
+    }
+
+    let crossings = descending_edges
+        .iter()
+        .cartesian_product(ascending_edges.iter())
+        .filter(|(a, b)| (*a + *b) % 2 == 0)
+        .map(|(a, b)| (a + b) / 2);
+
+    // rows where the edges cross the x = 0 and x = search_limit borders
+    let left_border_crossings = descending_edges.iter().chain(ascending_edges.iter()).copied();
+    let right_border_crossings = descending_edges
+        .iter()
+        .map(|a| a + search_limit)
+        .chain(ascending_edges.iter().map(|b| b - search_limit));
+
+    crossings
+        .chain(left_border_crossings)
+        .chain(right_border_crossings)
+        .chain([0, search_limit])
+        .collect()
+}
+
+fn get_tuning_frequency(beacon: Position) -> PuzzleResult {
+    (beacon.x as PuzzleResult * 4000000) + beacon.y as PuzzleResult
+}
@@ -0,0 +1,57 @@
+use crate::parse_error::{self, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Valve {
+    pub name: String,
+    pub flow_rate: u8,
+}
+
+#[derive(Debug)]
+pub struct Tunnel {
+    pub from_valve: String,
+    pub to_valve: String,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub valves: Vec<Valve>,
+    pub tunnels: Vec<Tunnel>,
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let valve_regex = Regex::new(r"Valve (.+) has flow rate=(\d+)").unwrap();
+    let tunnel_regex = Regex::new(r"tunnel[s]? lead[s]? to valve[s]? (.+)").unwrap();
+
+    let mut valves = Vec::new();
+    let mut tunnels = Vec::new();
+
+    for line in parse_error::lines(input) {
+        let captures = line.captures(&valve_regex)?;
+        let valve_name = &captures[1];
+        let valve_flow_rate = line.parse::<u8>(&captures[2])?;
+
+        let valve = Valve {
+            name: valve_name.to_string(),
+            flow_rate: valve_flow_rate,
+        };
+
+        valves.push(valve);
+
+        let captures = line.captures(&tunnel_regex)?;
+        let tunnel_to_valves = &captures[1];
+
+        let to_valves = tunnel_to_valves.split(", ").collect::<Vec<_>>();
+
+        for to_valve in to_valves {
+            let tunnel = Tunnel {
+                from_valve: valve_name.to_string(),
+                to_valve: to_valve.to_string(),
+            };
+
+            tunnels.push(tunnel);
+        }
+    }
+
+    Ok(Input { valves, tunnels })
+}
@@ -0,0 +1,26 @@
+pub mod input;
+pub mod part1;
+pub mod part2;
+pub mod tunnels;
+
+use crate::{parse_error::ParseError, solution::Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = input::Input;
+    type Part1 = part1::PuzzleResult;
+    type Part2 = part2::PuzzleResult;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::get_result(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::get_result(input)
+    }
+}
@@ -0,0 +1,130 @@
+use super::{
+    input::{Input, Valve},
+    tunnels::{extract_tunnel_crosses, TunnelCrosses},
+};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+struct ValveOpening {
+    valve: Valve,
+    minute: u8,
+}
+
+type OpenedValves = HashMap<String, Valve>;
+
+const TOTAL_MINUTES: u8 = 30;
+const MINUTE_TO_OPEN_VALVE: u8 = 1;
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let valves = &input.valves;
+
+    let tunnel_crosses = extract_tunnel_crosses(&input.tunnels, valves);
+
+    let opened_valves = OpenedValves::new();
+
+    let current_valve_name = String::from("AA");
+    let current_valve = valves
+        .iter()
+        .find(|valve| valve.name == current_valve_name)
+        .unwrap();
+
+    let valve_openings = get_best_valve_openings(
+        &tunnel_crosses,
+        valves,
+        current_valve,
+        &opened_valves,
+        TOTAL_MINUTES,
+    );
+
+    get_total_pressure_released(&valve_openings)
+}
+
+fn get_total_pressure_released(valve_openings: &[ValveOpening]) -> u32 {
+    valve_openings
+        .iter()
+        .map(|valve_opening| {
+            let valve = valve_opening.valve.clone();
+            let minute = valve_opening.minute;
+
+            let minutes_left = TOTAL_MINUTES - minute;
+
+            valve.flow_rate as u32 * minutes_left as u32
+        })
+        .sum::<u32>()
+}
+
+fn get_best_valve_openings(
+    tunnel_crosses: &TunnelCrosses,
+    valves: &Vec<Valve>,
+    current_valve: &Valve,
+    opened_valves: &OpenedValves,
+    remaining_minutes: u8,
+) -> Vec<ValveOpening> {
+    let closed_valves = valves
+        .iter()
+        .filter(|valve| !opened_valves.contains_key(&valve.name))
+        .collect::<Vec<_>>();
+
+    let next_possible_valves = closed_valves
+        .into_iter()
+        .filter(|valve| valve.flow_rate > 0)
+        .filter(|valve| valve.name != current_valve.name)
+        .collect::<Vec<_>>();
+
+    if next_possible_valves.is_empty() {
+        return vec![];
+    }
+
+    let best_valve_openings = next_possible_valves
+        .into_iter()
+        .map(|next_valve| {
+            let from = &current_valve.name;
+            let to = &next_valve.name;
+            let key = (from.clone(), to.clone());
+
+            let minutes_to_traverse = tunnel_crosses.get(&key).unwrap();
+
+            let total_minutes = remaining_minutes as i32
+                - *minutes_to_traverse as i32
+                - MINUTE_TO_OPEN_VALVE as i32;
+
+            if total_minutes <= 0 {
+                return (0, vec![]);
+            }
+
+            let valve_opening = ValveOpening {
+                valve: next_valve.clone(),
+                minute: TOTAL_MINUTES - total_minutes as u8,
+            };
+
+            let mut valve_openings = vec![valve_opening];
+
+            let mut next_opened_valves = opened_valves.clone();
+            next_opened_valves.insert(next_valve.name.clone(), next_valve.clone());
+
+            let next_valve_openings = get_best_valve_openings(
+                tunnel_crosses,
+                valves,
+                next_valve,
+                &next_opened_valves,
+                total_minutes as u8,
+            );
+
+            for valve_opening in next_valve_openings {
+                valve_openings.push(valve_opening);
+            }
+
+            let next_total_pressure = get_total_pressure_released(&valve_openings);
+
+            (next_total_pressure, valve_openings)
+        })
+        .sorted_by(|(a_pressure, _), (b_pressure, _)| b_pressure.cmp(a_pressure))
+        .map(|(_, valve_openings)| valve_openings)
+        .next()
+        .unwrap();
+
+    best_valve_openings
+}
@@ -0,0 +1,140 @@
+use super::{
+    input::{Input, Valve},
+    tunnels::{extract_tunnel_crosses, TunnelCrosses},
+};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Worker {
+    Me,
+    Elephant,
+}
+
+#[derive(Debug, Clone)]
+struct ValveOpener {
+    current_valve_name: String,
+    worker: Worker,
+    occupied_until_minute: u8,
+}
+
+type OpenedValves = HashMap<String, Valve>;
+
+const TOTAL_MINUTES: u8 = 26;
+const MINUTE_TO_OPEN_VALVE: u8 = 1;
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let valves = &input.valves;
+
+    let tunnel_crosses = extract_tunnel_crosses(&input.tunnels, valves);
+
+    let opened_valves = OpenedValves::new();
+
+    let valve_openers = vec![
+        ValveOpener {
+            current_valve_name: String::from("AA"),
+            worker: Worker::Me,
+            occupied_until_minute: 1,
+        },
+        ValveOpener {
+            current_valve_name: String::from("AA"),
+            worker: Worker::Elephant,
+            occupied_until_minute: 1,
+        },
+    ];
+
+    let openable_valves = valves
+        .iter()
+        .filter(|valve| valve.flow_rate > 0)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    get_best_total_pressure(
+        &tunnel_crosses,
+        &openable_valves,
+        &opened_valves,
+        &valve_openers,
+    )
+}
+
+fn calculate_pressure_released(valve: &Valve, from_minute: u8) -> u32 {
+    let minutes_left = (TOTAL_MINUTES + 1) - from_minute;
+    valve.flow_rate as u32 * minutes_left as u32
+}
+
+fn get_best_total_pressure(
+    tunnel_crosses: &TunnelCrosses,
+    openable_valves: &[Valve],
+    opened_valves: &OpenedValves,
+    valve_openers: &[ValveOpener],
+) -> u32 {
+    if openable_valves.is_empty() {
+        return 0;
+    }
+
+    let next_opener =
+        match valve_openers[0].occupied_until_minute <= valve_openers[1].occupied_until_minute {
+            true => &valve_openers[0],
+            _ => &valve_openers[1],
+        };
+
+    let best_total_pressure = openable_valves
+        .iter()
+        .map(|next_valve| {
+            let from = &next_opener.current_valve_name;
+            let to = &next_valve.name;
+            let key = (from.clone(), to.clone());
+
+            let minutes_to_traverse = tunnel_crosses.get(&key).unwrap();
+
+            let occupied_until_minute =
+                next_opener.occupied_until_minute + *minutes_to_traverse + MINUTE_TO_OPEN_VALVE;
+
+            if occupied_until_minute >= TOTAL_MINUTES {
+                return 0;
+            }
+
+            let next_valve_openers = valve_openers
+                .iter()
+                .map(|valve_opener| {
+                    if valve_opener.worker == next_opener.worker {
+                        return ValveOpener {
+                            current_valve_name: next_valve.name.clone(),
+                            worker: valve_opener.worker.clone(),
+                            occupied_until_minute,
+                        };
+                    }
+
+                    valve_opener.clone()
+                })
+                .collect::<Vec<_>>();
+
+            let mut next_opened_valves = opened_valves.clone();
+            next_opened_valves.insert(next_valve.name.clone(), next_valve.clone());
+
+            let valve_pressure_released =
+                calculate_pressure_released(next_valve, occupied_until_minute);
+
+            let next_openable_valves = openable_valves
+                .iter()
+                .filter(|valve| valve.name != next_valve.name)
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let next_total_pressure = get_best_total_pressure(
+                tunnel_crosses,
+                &next_openable_valves,
+                &next_opened_valves,
+                &next_valve_openers,
+            );
+
+            valve_pressure_released + next_total_pressure
+        })
+        .sorted_by(|a, b| b.cmp(a))
+        .next()
+        .unwrap();
+
+    best_total_pressure
+}
@@ -0,0 +1,31 @@
+use super::input::{Tunnel, Valve};
+use crate::pathfinding;
+use std::collections::HashMap;
+
+/// Number of tunnels to cross from a valve to another
+pub type TunnelCrosses = HashMap<(String, String), u8>;
+
+/// Number of tunnels to cross between any two valves
+pub fn extract_tunnel_crosses(tunnels: &[Tunnel], valves: &[Valve]) -> TunnelCrosses {
+    let mut graph = HashMap::<String, Vec<String>>::new();
+
+    for tunnel in tunnels.iter() {
+        graph
+            .entry(tunnel.from_valve.clone())
+            .or_default()
+            .push(tunnel.to_valve.clone());
+    }
+
+    let mut tunnel_crosses = TunnelCrosses::new();
+
+    for from_valve in valves.iter() {
+        let search = pathfinding::bfs(&graph, [from_valve.name.clone()], |_| false);
+
+        for (to_valve, number_of_crosses) in search.distances() {
+            let key = (from_valve.name.clone(), to_valve.clone());
+            tunnel_crosses.insert(key, number_of_crosses as u8);
+        }
+    }
+
+    tunnel_crosses
+}
@@ -0,0 +1,20 @@
+use crate::{
+    geometry::Direction4,
+    parse_error::{InputLine, ParseError},
+};
+
+pub type Input = Vec<Direction4>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let line = InputLine::new(0, input.trim());
+
+    line.text
+        .chars()
+        .enumerate()
+        .map(|(index, char)| match char {
+            '<' => Ok(Direction4::Left),
+            '>' => Ok(Direction4::Right),
+            _ => Err(line.error_at(index, "expected < or >")),
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
@@ -0,0 +1,158 @@
+use super::input::Input;
+use crate::{
+    geometry::{Direction4, Point2},
+    grid::SparseGrid,
+};
+
+type X = i64;
+type Y = i64;
+type Position = Point2<i64>;
+type Shape = Vec<Position>;
+
+/// Stopped rocks of the chamber, the y axis going up
+type VerticalChamber = SparseGrid<char>;
+
+const ROCK: char = '#';
+
+const CHAMBER_MIN_X: X = 0;
+const CHAMBER_MAX_X: X = 6;
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(directions: &Input) -> PuzzleResult {
+    let max_direction_index = directions.len();
+    let mut direction_index = 0;
+
+    const MAX_ROCKS_FALLING: u16 = 2022;
+
+    let mut chamber = VerticalChamber::new();
+    let mut tower_height: Y = 0;
+
+    for index in 0..MAX_ROCKS_FALLING {
+        let shape = create_shape(index);
+
+        let shape_width = shape.iter().map(|position| position.x).max().unwrap();
+        let shape_height = shape.iter().map(|position| position.y).max().unwrap();
+
+        const START_X: X = 2;
+        const START_Y: Y = 3;
+
+        let start_y = tower_height + START_Y + shape_height;
+        let mut position = Point2::new(START_X, start_y);
+
+        let max_x = CHAMBER_MAX_X - shape_width;
+
+        loop {
+            // try move left/right (min/max based on walls/stopped rock)
+            let direction = directions[direction_index];
+            position = try_push(position, direction, max_x, &chamber, &shape);
+
+            direction_index += 1;
+            direction_index %= max_direction_index;
+
+            // check has hit another rock OR has hit the floor
+            let next_position = position - Point2::new(0, 1);
+
+            if has_hit_rock_stopped(next_position, &chamber, &shape) {
+                break;
+            }
+
+            let lowest_y = next_position.y - shape_height;
+            let has_hit_the_floor = lowest_y < 0;
+            if has_hit_the_floor {
+                break;
+            }
+
+            position = next_position;
+        }
+
+        for shape_position in shape {
+            chamber.insert(position_in_chamber(position, shape_position), ROCK);
+        }
+
+        // the top of the shape is at the position of the rock
+        tower_height = tower_height.max(position.y + 1);
+    }
+
+    tower_height as PuzzleResult
+}
+
+/// Push the falling rock sideways, unless it would hit a wall or a stopped rock
+fn try_push(
+    position: Position,
+    direction: Direction4,
+    max_x: X,
+    chamber: &VerticalChamber,
+    shape: &Shape,
+) -> Position {
+    let new_position = position + direction.offset();
+
+    if new_position.x < CHAMBER_MIN_X || new_position.x > max_x {
+        return position;
+    }
+
+    if has_hit_rock_stopped(new_position, chamber, shape) {
+        return position;
+    }
+
+    new_position
+}
+
+fn has_hit_rock_stopped(position: Position, chamber: &VerticalChamber, shape: &Shape) -> bool {
+    shape
+        .iter()
+        .any(|shape_position| chamber.contains(position_in_chamber(position, *shape_position)))
+}
+
+/// Position of a part of the shape, the shape being drawn downward from its top left corner
+fn position_in_chamber(position: Position, shape_position: Position) -> Position {
+    Point2::new(position.x + shape_position.x, position.y - shape_position.y)
+}
+
+fn create_shape(index: u16) -> Shape {
+    const TOTAL_SHAPES: u16 = 5;
+
+    let index = index % TOTAL_SHAPES;
+    let mut shape = Shape::new();
+
+    match index {
+        0 => {
+            for x in 0..=3 {
+                shape.push(Point2::new(x, 0));
+            }
+        }
+        1 => {
+            for x in 0..=2 {
+                for y in 0..=2 {
+                    if x == 1 || y == 1 {
+                        shape.push(Point2::new(x, y));
+                    }
+                }
+            }
+        }
+        2 => {
+            for x in 0..=2 {
+                for y in 0..=2 {
+                    if x == 2 || y == 2 {
+                        shape.push(Point2::new(x, y));
+                    }
+                }
+            }
+        }
+        3 => {
+            for y in 0..=3 {
+                shape.push(Point2::new(0, y));
+            }
+        }
+        4 => {
+            for x in 0..=1 {
+                for y in 0..=1 {
+                    shape.push(Point2::new(x, y));
+                }
+            }
+        }
+        _ => panic!("Invalid index"),
+    }
+
+    shape
+}
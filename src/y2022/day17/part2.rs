@@ -0,0 +1,214 @@
+use super::input::Input;
+use crate::{
+    cycle::{self, Metric},
+    geometry::{Direction4, Point2},
+    grid::SparseGrid,
+};
+
+type X = i64;
+type Y = i64;
+type Position = Point2<i64>;
+type Shape = Vec<Position>;
+
+/// Stopped rocks of the chamber, the y axis going up
+type VerticalChamber = SparseGrid<char>;
+
+/// Depth of the highest rock of each column, from the top of the tower
+type Surface = [Y; CHAMBER_WIDTH];
+
+/// Everything deciding how the next rocks will fall: the next shape, the next jet and the surface
+type ChamberState = (usize, usize, Surface);
+
+const ROCK: char = '#';
+
+const CHAMBER_MIN_X: X = 0;
+const CHAMBER_MAX_X: X = 6;
+const CHAMBER_WIDTH: usize = 7;
+
+const TOTAL_SHAPES: usize = 5;
+
+pub type PuzzleResult = u64;
+
+pub fn get_result(directions: &Input) -> PuzzleResult {
+    const MAX_ROCKS_FALLING: usize = 1_000_000_000_000;
+
+    let shapes = (0..TOTAL_SHAPES).map(create_shape).collect::<Vec<_>>();
+
+    let mut chamber = VerticalChamber::new();
+    let mut tower_height: Metric = 0;
+    let mut shape_index = 0;
+    let mut direction_index = 0;
+
+    let initial_state = (
+        shape_index,
+        direction_index,
+        get_surface(&chamber, tower_height),
+    );
+
+    let cycle = cycle::find_cycle((initial_state, 0), || {
+        drop_rock(
+            &mut chamber,
+            &mut tower_height,
+            &shapes[shape_index],
+            directions,
+            &mut direction_index,
+        );
+
+        shape_index = (shape_index + 1) % TOTAL_SHAPES;
+
+        let state: ChamberState = (
+            shape_index,
+            direction_index,
+            get_surface(&chamber, tower_height),
+        );
+
+        (state, tower_height)
+    });
+
+    cycle.extrapolate(MAX_ROCKS_FALLING) as PuzzleResult
+}
+
+/// Let a rock fall until it stops, pushed by the jets along the way, then raise the tower height
+fn drop_rock(
+    chamber: &mut VerticalChamber,
+    tower_height: &mut Metric,
+    shape: &Shape,
+    directions: &Input,
+    direction_index: &mut usize,
+) {
+    let shape_width = shape.iter().map(|position| position.x).max().unwrap();
+    let shape_height = shape.iter().map(|position| position.y).max().unwrap();
+
+    const START_X: X = 2;
+    const START_Y: Y = 3;
+
+    let start_y = *tower_height + START_Y + shape_height;
+    let mut position = Point2::new(START_X, start_y);
+
+    let max_x = CHAMBER_MAX_X - shape_width;
+
+    loop {
+        // try move left/right (min/max based on walls/stopped rock)
+        let direction = directions[*direction_index];
+        position = try_push(position, direction, max_x, chamber, shape);
+
+        *direction_index += 1;
+        *direction_index %= directions.len();
+
+        // check has hit another rock OR has hit the floor
+        let next_position = position - Point2::new(0, 1);
+
+        if has_hit_rock_stopped(next_position, chamber, shape) {
+            break;
+        }
+
+        let lowest_y = next_position.y - shape_height;
+        let has_hit_the_floor = lowest_y < 0;
+        if has_hit_the_floor {
+            break;
+        }
+
+        position = next_position;
+    }
+
+    for shape_position in shape {
+        chamber.insert(position_in_chamber(position, *shape_position), ROCK);
+    }
+
+    // the top of the shape is at the position of the rock
+    *tower_height = (*tower_height).max(position.y + 1);
+}
+
+fn get_surface(chamber: &VerticalChamber, tower_height: Metric) -> Surface {
+    let mut surface = [tower_height; CHAMBER_WIDTH];
+
+    for (x, depth) in surface.iter_mut().enumerate() {
+        let highest_rock_y = (0..tower_height)
+            .rev()
+            .find(|y| chamber.contains(Point2::new(x as X, *y)));
+
+        if let Some(highest_rock_y) = highest_rock_y {
+            *depth = tower_height - 1 - highest_rock_y;
+        }
+    }
+
+    surface
+}
+
+/// Push the falling rock sideways, unless it would hit a wall or a stopped rock
+fn try_push(
+    position: Position,
+    direction: Direction4,
+    max_x: X,
+    chamber: &VerticalChamber,
+    shape: &Shape,
+) -> Position {
+    let new_position = position + direction.offset();
+
+    if new_position.x < CHAMBER_MIN_X || new_position.x > max_x {
+        return position;
+    }
+
+    if has_hit_rock_stopped(new_position, chamber, shape) {
+        return position;
+    }
+
+    new_position
+}
+
+fn has_hit_rock_stopped(position: Position, chamber: &VerticalChamber, shape: &Shape) -> bool {
+    shape
+        .iter()
+        .any(|shape_position| chamber.contains(position_in_chamber(position, *shape_position)))
+}
+
+/// Position of a part of the shape, the shape being drawn downward from its top left corner
+fn position_in_chamber(position: Position, shape_position: Position) -> Position {
+    Point2::new(position.x + shape_position.x, position.y - shape_position.y)
+}
+
+fn create_shape(index: usize) -> Shape {
+    let index = index % TOTAL_SHAPES;
+    let mut shape = Shape::new();
+
+    match index {
+        0 => {
+            for x in 0..=3 {
+                shape.push(Point2::new(x, 0));
+            }
+        }
+        1 => {
+            for x in 0..=2 {
+                for y in 0..=2 {
+                    if x == 1 || y == 1 {
+                        shape.push(Point2::new(x, y));
+                    }
+                }
+            }
+        }
+        2 => {
+            for x in 0..=2 {
+                for y in 0..=2 {
+                    if x == 2 || y == 2 {
+                        shape.push(Point2::new(x, y));
+                    }
+                }
+            }
+        }
+        3 => {
+            for y in 0..=3 {
+                shape.push(Point2::new(0, y));
+            }
+        }
+        4 => {
+            for x in 0..=1 {
+                for y in 0..=1 {
+                    shape.push(Point2::new(x, y));
+                }
+            }
+        }
+        _ => panic!("Invalid index"),
+    }
+
+    shape
+}
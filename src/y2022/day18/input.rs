@@ -0,0 +1,26 @@
+use crate::{
+    geometry::Point3,
+    parse_error::{self, ParseError},
+};
+
+pub type Axe = i8;
+pub type Cube = Point3<Axe>;
+
+pub type Input = Vec<Cube>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    parse_error::lines(input)
+        .map(|line| {
+            let axes = line.text.split(',').collect::<Vec<_>>();
+            if axes.len() != 3 {
+                return Err(line.error_at(0, "expected a cube like x,y,z"));
+            }
+
+            let x = line.parse::<Axe>(axes[0])?;
+            let y = line.parse::<Axe>(axes[1])?;
+            let z = line.parse::<Axe>(axes[2])?;
+
+            Ok(Cube::new(x, y, z))
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
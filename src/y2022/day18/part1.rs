@@ -0,0 +1,19 @@
+use super::input::Input;
+
+pub type PuzzleResult = u16;
+
+pub fn get_result(cubes: &Input) -> PuzzleResult {
+    let mut total_surface_area: PuzzleResult = 0;
+
+    for cube in cubes.iter() {
+        let exposed_sides = cube
+            .neighbours()
+            .iter()
+            .filter(|neighbour| !cubes.contains(neighbour))
+            .count();
+
+        total_surface_area += exposed_sides as PuzzleResult;
+    }
+
+    total_surface_area
+}
@@ -0,0 +1,55 @@
+use super::input::{Cube, Input};
+use std::collections::{HashSet, VecDeque};
+
+pub type PuzzleResult = u16;
+
+pub fn get_result(cubes: &Input) -> PuzzleResult {
+    let min_x = cubes.iter().map(|cube| cube.x).min().unwrap();
+    let max_x = cubes.iter().map(|cube| cube.x).max().unwrap();
+
+    let min_y = cubes.iter().map(|cube| cube.y).min().unwrap();
+    let max_y = cubes.iter().map(|cube| cube.y).max().unwrap();
+
+    let min_z = cubes.iter().map(|cube| cube.z).min().unwrap();
+    let max_z = cubes.iter().map(|cube| cube.z).max().unwrap();
+
+    const START_POSITION: Cube = Cube { x: 1, y: 1, z: 1 };
+
+    let mut next_positions_to_check = VecDeque::from(vec![START_POSITION]);
+
+    let mut visited_positions = HashSet::new();
+    visited_positions.insert(START_POSITION);
+
+    let mut exterior_surface_area: PuzzleResult = 0;
+
+    while let Some(current_position) = next_positions_to_check.pop_front() {
+        for next_position in current_position.neighbours() {
+            let Cube { x, y, z } = next_position;
+
+            let is_outside = x < min_x - 1
+                || x > max_x + 1
+                || y < min_y - 1
+                || y > max_y + 1
+                || z < min_z - 1
+                || z > max_z + 1;
+            if is_outside {
+                continue;
+            }
+
+            let has_hit_cube = cubes.contains(&next_position);
+            if has_hit_cube {
+                exterior_surface_area += 1;
+                visited_positions.insert(next_position);
+                continue;
+            }
+
+            let is_already_visited = visited_positions.contains(&next_position);
+            if !is_already_visited {
+                visited_positions.insert(next_position);
+                next_positions_to_check.push_back(next_position);
+            }
+        }
+    }
+
+    exterior_surface_area
+}
@@ -0,0 +1,92 @@
+use crate::parse_error::{self, ParseError};
+use regex::Regex;
+use std::ops::Add;
+
+#[derive(Debug, Clone)]
+pub struct Cost {
+    pub ore: Option<u8>,
+    pub clay: Option<u8>,
+    pub obsidian: Option<u8>,
+}
+
+impl Add for Cost {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            ore: match (self.ore, other.ore) {
+                (Some(a), Some(b)) => Some(a + b),
+                (Some(a), None) => Some(a),
+                (None, Some(b)) => Some(b),
+                (None, None) => None,
+            },
+            clay: match (self.clay, other.clay) {
+                (Some(a), Some(b)) => Some(a + b),
+                (Some(a), None) => Some(a),
+                (None, Some(b)) => Some(b),
+                (None, None) => None,
+            },
+            obsidian: match (self.obsidian, other.obsidian) {
+                (Some(a), Some(b)) => Some(a + b),
+                (Some(a), None) => Some(a),
+                (None, Some(b)) => Some(b),
+                (None, None) => None,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    pub id: u8,
+    pub ore_robot_cost: Cost,
+    pub clay_robot_cost: Cost,
+    pub obsidian_robot_cost: Cost,
+    pub geode_robot_cost: Cost,
+}
+
+pub type Input = Vec<Blueprint>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let regex = Regex::new(r"Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.").unwrap();
+
+    parse_error::lines(input)
+        .map(|line| {
+            let captures = line.captures(&regex)?;
+
+            let id = line.parse::<u8>(&captures[1])?;
+
+            let ore_robot_cost = Cost {
+                ore: Some(line.parse::<u8>(&captures[2])?),
+                clay: None,
+                obsidian: None,
+            };
+
+            let clay_robot_cost = Cost {
+                ore: Some(line.parse::<u8>(&captures[3])?),
+                clay: None,
+                obsidian: None,
+            };
+
+            let obsidian_robot_cost = Cost {
+                ore: Some(line.parse::<u8>(&captures[4])?),
+                clay: Some(line.parse::<u8>(&captures[5])?),
+                obsidian: None,
+            };
+
+            let geode_robot_cost = Cost {
+                ore: Some(line.parse::<u8>(&captures[6])?),
+                clay: None,
+                obsidian: Some(line.parse::<u8>(&captures[7])?),
+            };
+
+            Ok(Blueprint {
+                id,
+                ore_robot_cost,
+                clay_robot_cost,
+                obsidian_robot_cost,
+                geode_robot_cost,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
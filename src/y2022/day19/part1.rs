@@ -1,49 +1,5 @@
+use super::input::{Blueprint, Cost, Input};
 use itertools::Itertools;
-use regex::Regex;
-use std::{ops::Add, str::Lines};
-
-#[derive(Debug, Clone)]
-struct Cost {
-    ore: Option<u8>,
-    clay: Option<u8>,
-    obsidian: Option<u8>,
-}
-
-impl Add for Cost {
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self {
-        Self {
-            ore: match (self.ore, other.ore) {
-                (Some(a), Some(b)) => Some(a + b),
-                (Some(a), None) => Some(a),
-                (None, Some(b)) => Some(b),
-                (None, None) => None,
-            },
-            clay: match (self.clay, other.clay) {
-                (Some(a), Some(b)) => Some(a + b),
-                (Some(a), None) => Some(a),
-                (None, Some(b)) => Some(b),
-                (None, None) => None,
-            },
-            obsidian: match (self.obsidian, other.obsidian) {
-                (Some(a), Some(b)) => Some(a + b),
-                (Some(a), None) => Some(a),
-                (None, Some(b)) => Some(b),
-                (None, None) => None,
-            },
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Blueprint {
-    id: u8,
-    ore_robot_cost: Cost,
-    clay_robot_cost: Cost,
-    obsidian_robot_cost: Cost,
-    geode_robot_cost: Cost,
-}
 
 #[derive(Debug, Clone)]
 struct Resources {
@@ -64,13 +20,11 @@ enum ResourceType {
 type Action = Vec<ResourceType>;
 type Actions = Vec<Action>;
 
-const TOTAL_MINUTES: u8 = 24;
-
-pub fn run(input: &str) {
-    let lines = input.lines();
+pub type PuzzleResult = u16;
 
-    let blueprints = parse_input(lines);
+const TOTAL_MINUTES: u8 = 24;
 
+pub fn get_result(blueprints: &Input) -> PuzzleResult {
     const INITIAL_ROBOTS: Resources = Resources {
         ore: 1,
         clay: 0,
@@ -131,12 +85,12 @@ pub fn run(input: &str) {
         })
         .collect::<Vec<_>>();
 
-    let quality_levels = best_number_of_geodes_per_blueprint
+    best_number_of_geodes_per_blueprint
         .iter()
-        .map(|(blueprint, number_of_geodes)| blueprint.id as u16 * number_of_geodes.clone() as u16)
-        .sum::<u16>();
-
-    println!("{:?}", quality_levels);
+        .map(|(blueprint, number_of_geodes)| {
+            blueprint.id as PuzzleResult * *number_of_geodes as PuzzleResult
+        })
+        .sum::<PuzzleResult>()
 }
 
 fn calculate_best_number_of_geodes(
@@ -158,27 +112,21 @@ fn calculate_best_number_of_geodes(
     let has_reached_max_clay_production = robots.clay >= max_clay_production;
     let has_reached_max_obsidian_production = robots.obsidian >= max_obsidian_production;
 
-    let can_build_ore = can_build(&blueprint.ore_robot_cost, &resources);
-    let can_build_clay = can_build(&blueprint.clay_robot_cost, &resources);
-    let can_build_obsidian = can_build(&blueprint.obsidian_robot_cost, &resources);
-    let can_build_geode = can_build(&blueprint.geode_robot_cost, &resources);
+    let can_build_ore = can_build(&blueprint.ore_robot_cost, resources);
+    let can_build_clay = can_build(&blueprint.clay_robot_cost, resources);
+    let can_build_obsidian = can_build(&blueprint.obsidian_robot_cost, resources);
+    let can_build_geode = can_build(&blueprint.geode_robot_cost, resources);
 
-    if !has_reached_max_ore_production {
-        if can_build_ore {
-            actions.push(vec![ResourceType::Ore]);
-        }
+    if !has_reached_max_ore_production && can_build_ore {
+        actions.push(vec![ResourceType::Ore]);
     }
 
-    if !has_reached_max_clay_production {
-        if can_build_clay {
-            actions.push(vec![ResourceType::Clay]);
-        }
+    if !has_reached_max_clay_production && can_build_clay {
+        actions.push(vec![ResourceType::Clay]);
     }
 
-    if !has_reached_max_obsidian_production {
-        if can_build_obsidian {
-            actions.push(vec![ResourceType::Obsidian]);
-        }
+    if !has_reached_max_obsidian_production && can_build_obsidian {
+        actions.push(vec![ResourceType::Obsidian]);
     }
 
     if can_build_geode {
@@ -220,8 +168,8 @@ fn calculate_best_number_of_geodes(
         .map(|(robots, resources)| {
             calculate_best_number_of_geodes(
                 blueprint,
-                &robots,
-                &resources,
+                robots,
+                resources,
                 max_ore_production,
                 max_clay_production,
                 max_obsidian_production,
@@ -229,7 +177,7 @@ fn calculate_best_number_of_geodes(
             )
         })
         .sorted_by(|a, b| b.cmp(a))
-        .nth(0)
+        .next()
         .unwrap();
 
     most_geodes
@@ -301,49 +249,3 @@ fn can_build(cost: &Cost, resources: &Resources) -> bool {
 
     has_enough_ore && has_enough_clay && has_enough_obsidian
 }
-
-fn parse_input(lines: Lines) -> Vec<Blueprint> {
-    let blueprints = lines.map(
-        |line| {
-            let regex = Regex::new(r"Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.").unwrap();
-            let captures = regex.captures(line).unwrap();
-
-            let id = captures.get(1).unwrap().as_str().parse::<u8>().unwrap();
-
-            let ore_robot_cost = Cost {
-                ore: captures.get(2).unwrap().as_str().parse::<u8>().ok(),
-                clay: None,
-                obsidian: None,
-            };
-
-            let clay_robot_cost = Cost {
-                ore: captures.get(3).unwrap().as_str().parse::<u8>().ok(),
-                clay: None,
-                obsidian: None,
-            };
-
-            let obsidian_robot_cost = Cost {
-                ore: captures.get(4).unwrap().as_str().parse::<u8>().ok(),
-                clay: captures.get(5).unwrap().as_str().parse::<u8>().ok(),
-                obsidian: None,
-            };
-
-            let geode_robot_cost = Cost {
-                ore: captures.get(6).unwrap().as_str().parse::<u8>().ok(),
-                clay: None,
-                obsidian: captures.get(7).unwrap().as_str().parse::<u8>().ok(),
-            };
-
-            Blueprint {
-                id,
-                ore_robot_cost,
-                clay_robot_cost,
-                obsidian_robot_cost,
-                geode_robot_cost,
-            }
-        }
-    )
-    .collect::<Vec<_>>();
-
-    blueprints
-}
@@ -1,50 +1,7 @@
+use super::input::{Blueprint, Cost, Input};
 use itertools::Itertools;
 use rayon::iter::ParallelIterator;
 use rayon::prelude::IntoParallelRefIterator;
-use regex::Regex;
-use std::{ops::Add, str::Lines};
-
-#[derive(Debug, Clone)]
-struct Cost {
-    ore: Option<u8>,
-    clay: Option<u8>,
-    obsidian: Option<u8>,
-}
-
-impl Add for Cost {
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self {
-        Self {
-            ore: match (self.ore, other.ore) {
-                (Some(a), Some(b)) => Some(a + b),
-                (Some(a), None) => Some(a),
-                (None, Some(b)) => Some(b),
-                (None, None) => None,
-            },
-            clay: match (self.clay, other.clay) {
-                (Some(a), Some(b)) => Some(a + b),
-                (Some(a), None) => Some(a),
-                (None, Some(b)) => Some(b),
-                (None, None) => None,
-            },
-            obsidian: match (self.obsidian, other.obsidian) {
-                (Some(a), Some(b)) => Some(a + b),
-                (Some(a), None) => Some(a),
-                (None, Some(b)) => Some(b),
-                (None, None) => None,
-            },
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Blueprint {
-    ore_robot_cost: Cost,
-    clay_robot_cost: Cost,
-    obsidian_robot_cost: Cost,
-    geode_robot_cost: Cost,
-}
 
 #[derive(Debug, Clone)]
 struct Resources {
@@ -65,13 +22,12 @@ enum ResourceType {
 type Action = Vec<ResourceType>;
 type Actions = Vec<Action>;
 
-const TOTAL_MINUTES: u8 = 32;
+pub type PuzzleResult = u16;
 
-pub fn run(input: &str) {
-    let lines = input.lines();
+const TOTAL_MINUTES: u8 = 32;
 
-    let blueprints = parse_input(lines);
-    let first_three_blueprints = blueprints.into_iter().take(3).collect::<Vec<_>>();
+pub fn get_result(blueprints: &Input) -> PuzzleResult {
+    let first_three_blueprints = blueprints.iter().take(3).collect::<Vec<_>>();
 
     const INITIAL_ROBOTS: Resources = Resources {
         ore: 1,
@@ -120,7 +76,7 @@ pub fn run(input: &str) {
             .max()
             .unwrap();
 
-            let best_number_of_geodes = calculate_best_number_of_geodes(
+            calculate_best_number_of_geodes(
                 blueprint,
                 &INITIAL_ROBOTS,
                 &INITIAL_RESOURCES,
@@ -128,18 +84,15 @@ pub fn run(input: &str) {
                 max_clay_production,
                 max_obsidian_production,
                 1,
-            );
-            best_number_of_geodes
+            )
         })
         .collect::<Vec<_>>();
 
-    let total = best_number_of_geodes_per_blueprint
+    best_number_of_geodes_per_blueprint
         .into_iter()
         .fold(1, |total, number_of_geodes| {
-            total as u16 * number_of_geodes as u16
-        });
-
-    println!("{:?}", total);
+            total * number_of_geodes as PuzzleResult
+        })
 }
 
 fn calculate_best_number_of_geodes(
@@ -168,10 +121,10 @@ fn calculate_best_number_of_geodes(
         let has_reached_max_clay_production = robots.clay >= max_clay_production;
         let has_reached_max_obsidian_production = robots.obsidian >= max_obsidian_production;
 
-        let can_build_ore = can_build(&blueprint.ore_robot_cost, &resources);
-        let can_build_clay = can_build(&blueprint.clay_robot_cost, &resources);
-        let can_build_obsidian = can_build(&blueprint.obsidian_robot_cost, &resources);
-        let can_build_geode = can_build(&blueprint.geode_robot_cost, &resources);
+        let can_build_ore = can_build(&blueprint.ore_robot_cost, resources);
+        let can_build_clay = can_build(&blueprint.clay_robot_cost, resources);
+        let can_build_obsidian = can_build(&blueprint.obsidian_robot_cost, resources);
+        let can_build_geode = can_build(&blueprint.geode_robot_cost, resources);
 
         if !has_reached_max_ore_production && can_build_ore {
             actions.push(vec![ResourceType::Ore]);
@@ -240,8 +193,8 @@ fn calculate_best_number_of_geodes(
         .map(|(robots, resources)| {
             calculate_best_number_of_geodes(
                 blueprint,
-                &robots,
-                &resources,
+                robots,
+                resources,
                 max_ore_production,
                 max_clay_production,
                 max_obsidian_production,
@@ -251,7 +204,7 @@ fn calculate_best_number_of_geodes(
         .collect::<Vec<_>>()
         .into_iter()
         .sorted_by(|a, b| b.cmp(a))
-        .nth(0)
+        .next()
         .unwrap();
 
     most_geodes
@@ -323,48 +276,3 @@ fn can_build(cost: &Cost, resources: &Resources) -> bool {
 
     has_enough_ore && has_enough_clay && has_enough_obsidian
 }
-
-fn parse_input(lines: Lines) -> Vec<Blueprint> {
-    let blueprints = lines.map(
-        |line| {
-            let regex = Regex::new(r"Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.").unwrap();
-            let captures = regex.captures(line).unwrap();
-
-            let _id = captures.get(1).unwrap().as_str().parse::<u8>().unwrap();
-
-            let ore_robot_cost = Cost {
-                ore: captures.get(2).unwrap().as_str().parse::<u8>().ok(),
-                clay: None,
-                obsidian: None,
-            };
-
-            let clay_robot_cost = Cost {
-                ore: captures.get(3).unwrap().as_str().parse::<u8>().ok(),
-                clay: None,
-                obsidian: None,
-            };
-
-            let obsidian_robot_cost = Cost {
-                ore: captures.get(4).unwrap().as_str().parse::<u8>().ok(),
-                clay: captures.get(5).unwrap().as_str().parse::<u8>().ok(),
-                obsidian: None,
-            };
-
-            let geode_robot_cost = Cost {
-                ore: captures.get(6).unwrap().as_str().parse::<u8>().ok(),
-                clay: None,
-                obsidian: captures.get(7).unwrap().as_str().parse::<u8>().ok(),
-            };
-
-            Blueprint {
-                ore_robot_cost,
-                clay_robot_cost,
-                obsidian_robot_cost,
-                geode_robot_cost,
-            }
-        }
-    )
-    .collect::<Vec<_>>();
-
-    blueprints
-}
@@ -0,0 +1,26 @@
+use crate::parse_error::{self, ParseError};
+
+pub type Number = i64;
+
+#[derive(Debug, Clone)]
+pub struct Item {
+    pub value: Number,
+    pub original_position: usize,
+}
+
+pub type Input = Vec<Item>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut numbers: Vec<Item> = Vec::new();
+
+    for line in parse_error::lines(input) {
+        let value = line.parse::<Number>(line.text)?;
+
+        numbers.push(Item {
+            value,
+            original_position: line.index,
+        });
+    }
+
+    Ok(numbers)
+}
@@ -1,17 +1,9 @@
-use std::str::Lines;
+use super::input::{Input, Number};
 
-type Number = i16;
+pub type PuzzleResult = Number;
 
-#[derive(Debug)]
-struct Item {
-    value: Number,
-    original_position: usize,
-}
-
-pub fn run(input: &str) {
-    let lines = input.lines();
-
-    let mut arrangement = parse_input(lines);
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let mut arrangement = input.clone();
 
     let arrangement_length = arrangement.len();
 
@@ -29,12 +21,11 @@ pub fn run(input: &str) {
         }
 
         let new_index = current_index as Number + number_to_move;
-        let new_index = ((new_index as Number % message_size as Number) + message_size as Number)
-            as usize
-            % message_size;
+        let new_index =
+            ((new_index % message_size as Number) + message_size as Number) as usize % message_size;
 
         let number = arrangement.remove(current_index);
-        arrangement.insert(new_index as usize, number);
+        arrangement.insert(new_index, number);
     }
 
     let final_arrangement = arrangement;
@@ -46,21 +37,5 @@ pub fn run(input: &str) {
     let three_thousand_number =
         final_arrangement[(zero_position + 3000) % arrangement_length].value;
 
-    let total = thousand_number + two_thousand_number + three_thousand_number;
-    println!("{}", total);
-}
-
-fn parse_input(lines: Lines) -> Vec<Item> {
-    let mut numbers: Vec<Item> = Vec::new();
-
-    for (original_position, line) in lines.enumerate() {
-        let value = line.parse::<_>().unwrap();
-
-        numbers.push(Item {
-            value,
-            original_position,
-        });
-    }
-
-    return numbers;
+    thousand_number + two_thousand_number + three_thousand_number
 }
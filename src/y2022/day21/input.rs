@@ -0,0 +1,82 @@
+use crate::parse_error::{self, ParseError};
+
+pub type MonkeyName = String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operator {
+    Add,
+    Substract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    pub operator: Operator,
+    pub left: MonkeyName,
+    pub right: MonkeyName,
+}
+
+#[derive(Debug, Clone)]
+pub enum Job {
+    Number(u16),
+    Operation(Operation),
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub name: MonkeyName,
+    pub job: Job,
+}
+
+pub type Input = Vec<Monkey>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut monkeys = Vec::new();
+
+    for line in parse_error::lines(input) {
+        let (name, second_part) = line
+            .text
+            .split_once(':')
+            .ok_or_else(|| line.missing("expected a monkey name followed by a colon"))?;
+
+        let name = name.to_string();
+        let second_part = second_part.trim();
+        let is_digit = second_part.chars().all(|c| c.is_ascii_digit());
+
+        let job = if is_digit {
+            let number = line.parse::<_>(second_part)?;
+            Job::Number(number)
+        } else {
+            let parts = second_part.split(' ').collect::<Vec<_>>();
+            if parts.len() != 3 {
+                return Err(line.error(second_part, "expected a number or an operation"));
+            }
+
+            let left = parts[0].to_string();
+            let operator_str = parts[1];
+            let right = parts[2].to_string();
+
+            let operator = match operator_str {
+                "+" => Operator::Add,
+                "-" => Operator::Substract,
+                "*" => Operator::Multiply,
+                "/" => Operator::Divide,
+                _ => return Err(line.error(operator_str, "expected +, -, * or /")),
+            };
+
+            let operation = Operation {
+                operator,
+                left,
+                right,
+            };
+
+            Job::Operation(operation)
+        };
+
+        let monkey = Monkey { name, job };
+        monkeys.push(monkey);
+    }
+
+    Ok(monkeys)
+}
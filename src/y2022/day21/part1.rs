@@ -0,0 +1,50 @@
+use super::input::{Input, Job, Operator};
+use std::collections::HashMap;
+
+pub type PuzzleResult = i64;
+
+pub fn get_result(monkeys: &Input) -> PuzzleResult {
+    let mut remaining_monkeys = monkeys.clone();
+
+    let mut values: HashMap<String, PuzzleResult> = HashMap::new();
+
+    loop {
+        let mut index = 0;
+
+        while index < remaining_monkeys.len() {
+            let monkey = &remaining_monkeys[index];
+
+            if let Job::Number(value) = monkey.job {
+                values.insert(monkey.name.clone(), value as PuzzleResult);
+                remaining_monkeys.remove(index);
+                continue;
+            }
+
+            if let Job::Operation(operation) = &monkey.job {
+                let left = values.get(&operation.left);
+                let right = values.get(&operation.right);
+
+                if let (Some(&left), Some(&right)) = (left, right) {
+                    let result = match operation.operator {
+                        Operator::Add => left + right,
+                        Operator::Substract => left - right,
+                        Operator::Multiply => left * right,
+                        Operator::Divide => left / right,
+                    };
+
+                    values.insert(monkey.name.clone(), result);
+                    remaining_monkeys.remove(index);
+                    continue;
+                }
+            }
+
+            index += 1;
+        }
+
+        if remaining_monkeys.is_empty() {
+            break;
+        }
+    }
+
+    *values.get("root").unwrap()
+}
@@ -1,34 +1,5 @@
-use std::{collections::HashMap, str::Lines};
-
-type MonkeyName = String;
-
-#[derive(Debug, Clone, PartialEq)]
-enum Operator {
-    Add,
-    Substract,
-    Multiply,
-    Divide,
-    Match,
-}
-
-#[derive(Debug, Clone)]
-struct Operation {
-    operator: Operator,
-    left: MonkeyName,
-    right: MonkeyName,
-}
-
-#[derive(Debug, Clone)]
-enum Job {
-    Number(u16),
-    Operation(Operation),
-}
-
-#[derive(Debug, Clone)]
-struct Monkey {
-    name: MonkeyName,
-    job: Job,
-}
+use super::input::{Input, Job, Monkey, MonkeyName, Operator};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
 enum JobOwner {
@@ -38,16 +9,24 @@ enum JobOwner {
 
 type NumberResult = i128;
 
-pub fn run(input: &str) {
-    let lines = input.lines();
+pub type PuzzleResult = NumberResult;
+
+const ROOT_MONKEY_NAME: &str = "root";
+const MY_NAME: &str = "humn";
 
-    let owners = parse_input(lines);
-    let result = get_yell_number(&owners);
+pub fn get_result(monkeys: &Input) -> PuzzleResult {
+    let owners = monkeys
+        .iter()
+        .map(|monkey| match monkey.name.as_str() {
+            MY_NAME => JobOwner::Me(monkey.name.clone()),
+            _ => JobOwner::Monkey(monkey.clone()),
+        })
+        .collect::<Vec<_>>();
 
-    println!("{:?}", result);
+    get_yell_number(&owners).expect("No number to yell found")
 }
 
-fn get_yell_number(owners: &Vec<JobOwner>) -> Option<NumberResult> {
+fn get_yell_number(owners: &[JobOwner]) -> Option<NumberResult> {
     let min = i64::MIN;
     let max = i64::MAX;
 
@@ -81,12 +60,12 @@ fn get_yell_number(owners: &Vec<JobOwner>) -> Option<NumberResult> {
 
         let is_excluded = excluded_values.contains(&x);
         if is_excluded {
-            x = x - 1; // try left
+            x -= 1; // try left
         }
 
         let is_excluded = excluded_values.contains(&x);
         if is_excluded {
-            x = x + 2; // try right
+            x += 2; // try right
         }
 
         if is_excluded {
@@ -141,10 +120,10 @@ fn get_yell_number(owners: &Vec<JobOwner>) -> Option<NumberResult> {
 
 fn execute_jobs(
     x: i64,
-    owners: &Vec<JobOwner>,
+    owners: &[JobOwner],
     allow_div_error: bool,
 ) -> Result<NumberResult, Option<NumberResult>> {
-    let mut remaining_owners = owners.clone();
+    let mut remaining_owners = owners.to_vec();
     let mut values: HashMap<String, NumberResult> = HashMap::new();
 
     let mut has_div_error = false;
@@ -172,11 +151,8 @@ fn execute_jobs(
                     let left = values.get(&operation.left);
                     let right = values.get(&operation.right);
 
-                    if left.is_some() && right.is_some() {
-                        let left = *left.unwrap();
-                        let right = *right.unwrap();
-
-                        if monkey.name == "root" && operation.operator == Operator::Match {
+                    if let (Some(&left), Some(&right)) = (left, right) {
+                        if monkey.name == ROOT_MONKEY_NAME {
                             let diff = (left - right).abs();
 
                             if !allow_div_error && has_div_error {
@@ -198,7 +174,6 @@ fn execute_jobs(
 
                                 left / right
                             }
-                            _ => panic!("Invalid operator"),
                         };
 
                         values.insert(monkey.name.clone(), result);
@@ -218,60 +193,3 @@ fn execute_jobs(
 
     Err(None)
 }
-
-fn parse_input(lines: Lines) -> Vec<JobOwner> {
-    let mut owners = Vec::new();
-
-    for line in lines {
-        let mut parts = line.split(":");
-
-        let name = parts.next().unwrap().to_string();
-
-        let is_me = name == "humn";
-        if is_me {
-            owners.push(JobOwner::Me(name));
-            continue;
-        }
-
-        let is_root = name == "root";
-
-        let second_part = parts.next().unwrap().trim();
-        let is_digit = second_part.chars().all(|c| c.is_digit(10));
-
-        let job = if is_digit {
-            let number = second_part.parse::<_>().unwrap();
-            Job::Number(number)
-        } else {
-            let mut parts = second_part.split(" ");
-
-            let left = parts.next().unwrap().to_string();
-            let operator_str = parts.next().unwrap();
-            let right = parts.next().unwrap().to_string();
-
-            let operator = if is_root {
-                Operator::Match
-            } else {
-                match operator_str {
-                    "+" => Operator::Add,
-                    "-" => Operator::Substract,
-                    "*" => Operator::Multiply,
-                    "/" => Operator::Divide,
-                    _ => panic!("Unknown operator"),
-                }
-            };
-
-            let operation = Operation {
-                operator,
-                left,
-                right,
-            };
-
-            Job::Operation(operation)
-        };
-
-        let monkey = Monkey { name, job };
-        owners.push(JobOwner::Monkey(monkey));
-    }
-
-    owners
-}
@@ -0,0 +1,100 @@
+use crate::{
+    grid::{self, Grid},
+    parse_error::{self, InputLine, ParseError},
+};
+use regex::Regex;
+
+#[derive(Debug)]
+pub enum Tile {
+    Open,
+    Wall,
+}
+
+pub type X = usize;
+pub type Y = usize;
+pub type Position = grid::Position;
+
+/// Tiles of the board, `None` outside of it
+pub type Map = Grid<Option<Tile>>;
+
+#[derive(Debug)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub enum PathMove {
+    Forward(u8),
+    Turn(Turn),
+}
+
+pub type Path = Vec<PathMove>;
+
+/// Way the faces of the cube are laid out on the map
+#[derive(Debug, PartialEq)]
+pub enum CubeNet {
+    Sample,
+    Input,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub map: Map,
+    pub path: Path,
+    pub net: CubeNet,
+}
+
+pub fn parse_input(input: &str, net: CubeNet) -> Result<Input, ParseError> {
+    let lines = parse_error::lines(input).collect::<Vec<_>>();
+    let parts = lines
+        .split(|line| line.text.is_empty())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>();
+
+    if parts.len() < 2 {
+        let last_line = lines
+            .last()
+            .copied()
+            .unwrap_or_else(|| InputLine::new(0, ""));
+        return Err(last_line.missing("expected a map followed by a path"));
+    }
+
+    let map_part = parts[0];
+    let map = Grid::parse_lines(map_part.iter().copied(), "expected ., # or a space", |char| {
+        match char {
+            '.' => Some(Some(Tile::Open)),
+            '#' => Some(Some(Tile::Wall)),
+            ' ' => Some(None),
+            _ => None,
+        }
+    })?;
+
+    let path_part = parts[1];
+    let path = parse_path(path_part[0])?;
+
+    Ok(Input { map, path, net })
+}
+
+fn parse_path(line: InputLine) -> Result<Path, ParseError> {
+    let regex = Regex::new(r"(\d+|[R|L])").unwrap();
+
+    let mut path = Path::new();
+
+    for capture in regex.captures_iter(line.text) {
+        let capture = capture.get(0).unwrap().as_str();
+
+        let path_move = match capture {
+            "R" => PathMove::Turn(Turn::Right),
+            "L" => PathMove::Turn(Turn::Left),
+            _ => {
+                let moves = line.parse(capture)?;
+                PathMove::Forward(moves)
+            }
+        };
+
+        path.push(path_move);
+    }
+
+    Ok(path)
+}
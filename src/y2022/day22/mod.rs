@@ -0,0 +1,30 @@
+pub mod input;
+pub mod part1;
+pub mod part2;
+
+use crate::{parse_error::ParseError, solution::Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = input::Input;
+    type Part1 = part1::PuzzleResult;
+    type Part2 = part2::PuzzleResult;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_input(input, input::CubeNet::Input)
+    }
+
+    /// The sample is folded into a different cube net than the real input
+    fn parse_sample(input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_input(input, input::CubeNet::Sample)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::get_result(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::get_result(input)
+    }
+}
@@ -0,0 +1,86 @@
+use super::input::{Input, Map, PathMove, Position, Tile, Turn};
+use crate::geometry::{Direction4, Point2};
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let mut current_direction = Direction4::Right;
+
+    let start_x = input.map.row(0).unwrap().position(Option::is_some).unwrap();
+    let mut current_position = Point2::new(start_x, 0);
+
+    for path_move in input.path.iter() {
+        match path_move {
+            PathMove::Forward(moves) => {
+                for _ in 0..*moves {
+                    let next_position = input
+                        .map
+                        .neighbour(current_position, current_direction)
+                        .filter(|position| input.map[*position].is_some())
+                        .unwrap_or_else(|| {
+                            move_outside_board(&current_position, &current_direction, &input.map)
+                        });
+
+                    let tile = input.map[next_position].as_ref().unwrap();
+                    match tile {
+                        Tile::Open => {
+                            current_position = next_position;
+                        }
+                        Tile::Wall => {
+                            break;
+                        }
+                    }
+                }
+            }
+            PathMove::Turn(turn) => {
+                current_direction = match turn {
+                    Turn::Left => current_direction.turn_left(),
+                    Turn::Right => current_direction.turn_right(),
+                }
+            }
+        }
+    }
+
+    let facing = match current_direction {
+        Direction4::Right => 0,
+        Direction4::Down => 1,
+        Direction4::Left => 2,
+        Direction4::Up => 3,
+    };
+
+    let row = (current_position.y + 1) as u32;
+    let column = (current_position.x + 1) as u32;
+
+    (1000 * row) + (4 * column) + facing
+}
+
+fn move_outside_board(
+    current_position: &Position,
+    current_direction: &Direction4,
+    map: &Map,
+) -> Position {
+    let Point2 { x, y } = *current_position;
+
+    match current_direction {
+        Direction4::Right => {
+            let min_x = map.row(y).unwrap().position(Option::is_some).unwrap();
+
+            Point2::new(min_x, y)
+        }
+        Direction4::Down => {
+            let min_y = map.column(x).unwrap().position(Option::is_some).unwrap();
+
+            Point2::new(x, min_y)
+        }
+        Direction4::Left => {
+            let max_x = map.row(y).unwrap().rposition(Option::is_some).unwrap();
+
+            Point2::new(max_x, y)
+        }
+        Direction4::Up => {
+            let max_y = map.column(x).unwrap().rposition(Option::is_some).unwrap();
+
+            Point2::new(x, max_y)
+        }
+    }
+}
@@ -0,0 +1,466 @@
+use super::input::{CubeNet, Input, Map, PathMove, Position, Tile, Turn, X, Y};
+use crate::geometry::{Direction4, Point2};
+
+#[derive(Debug, PartialEq)]
+struct Region {
+    start_x: X,
+    start_y: Y,
+    end_x: X,
+    end_y: Y,
+}
+
+type Regions = Vec<Region>;
+
+pub type PuzzleResult = u32;
+
+const TOTAL_FACES: usize = 6;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let number_of_tiles = input.map.iter().filter(|(_, tile)| tile.is_some()).count();
+    let region_size = ((number_of_tiles / TOTAL_FACES) as f64).sqrt() as u8;
+    let is_sample_net = input.net == CubeNet::Sample;
+
+    let regions = extract_regions(&input.map, region_size);
+
+    let mut current_direction = Direction4::Right;
+
+    let start_x = input.map.row(0).unwrap().position(Option::is_some).unwrap();
+    let mut current_position = Point2::new(start_x, 0);
+
+    for path_move in input.path.iter() {
+        match path_move {
+            PathMove::Forward(moves) => {
+                for _ in 0..*moves {
+                    let next_position = input
+                        .map
+                        .neighbour(current_position, current_direction)
+                        .filter(|position| input.map[*position].is_some());
+
+                    let (next_position, next_direction) = match next_position {
+                        Some(next_position) => (next_position, current_direction),
+                        None => move_to_another_region(
+                            &current_position,
+                            &current_direction,
+                            &regions,
+                            is_sample_net,
+                        ),
+                    };
+
+                    let tile = input.map[next_position].as_ref().unwrap();
+                    match tile {
+                        Tile::Open => {
+                            current_position = next_position;
+                            current_direction = next_direction;
+                        }
+                        Tile::Wall => {
+                            break;
+                        }
+                    }
+                }
+            }
+            PathMove::Turn(turn) => {
+                current_direction = match turn {
+                    Turn::Left => current_direction.turn_left(),
+                    Turn::Right => current_direction.turn_right(),
+                }
+            }
+        }
+    }
+
+    let facing = match current_direction {
+        Direction4::Right => 0,
+        Direction4::Down => 1,
+        Direction4::Left => 2,
+        Direction4::Up => 3,
+    };
+
+    let row = (current_position.y + 1) as u32;
+    let column = (current_position.x + 1) as u32;
+
+    (1000 * row) + (4 * column) + facing
+}
+
+fn move_to_another_region(
+    current_position: &Position,
+    current_direction: &Direction4,
+    regions: &Regions,
+    is_sample_net: bool,
+) -> (Position, Direction4) {
+    let Point2 { x, y } = *current_position;
+
+    let current_region = regions
+        .iter()
+        .find(|region| {
+            let x_in_range = region.start_x <= x && x <= region.end_x;
+            let y_in_range = region.start_y <= y && y <= region.end_y;
+
+            x_in_range && y_in_range
+        })
+        .unwrap();
+
+    let face_1 = &regions[0];
+    let face_2 = &regions[1];
+    let face_3 = &regions[2];
+    let face_4 = &regions[3];
+    let face_5 = &regions[4];
+    let face_6 = &regions[5];
+
+    let normalized_x = x - current_region.start_x;
+    let inverted_x = current_region.end_x - x;
+
+    let normalized_y = y - current_region.start_y;
+    let inverted_y = current_region.end_y - y;
+
+    if is_sample_net {
+        if current_region == face_1 {
+            match current_direction {
+                Direction4::Right => {
+                    let next_region = face_6;
+
+                    let new_position = Point2::new(next_region.end_x, next_region.start_y + inverted_y);
+                    let new_direction = Direction4::Left;
+
+                    return (new_position, new_direction);
+                }
+                Direction4::Left => {
+                    let next_region = face_3;
+
+                    let new_position = Point2::new(next_region.start_x + normalized_y, next_region.start_y);
+                    let new_direction = Direction4::Down;
+
+                    return (new_position, new_direction);
+                }
+                Direction4::Up => {
+                    let next_region = face_2;
+
+                    let new_position = Point2::new(next_region.start_x + inverted_x, next_region.start_y);
+                    let new_direction = Direction4::Down;
+
+                    return (new_position, new_direction);
+                }
+                _ => {
+                    panic!("This should not happen!");
+                }
+            }
+        }
+        if current_region == face_2 {
+            match current_direction {
+                Direction4::Left => {
+                    let next_region = face_6;
+
+                    let new_position = Point2::new(next_region.start_x + inverted_y, next_region.end_y);
+                    let new_direction = Direction4::Up;
+
+                    return (new_position, new_direction);
+                }
+                Direction4::Up => {
+                    let next_region = face_1;
+
+                    let new_position = Point2::new(next_region.start_x + inverted_x, next_region.start_y);
+                    let new_direction = Direction4::Down;
+
+                    return (new_position, new_direction);
+                }
+                Direction4::Down => {
+                    let next_region = face_5;
+
+                    let new_position = Point2::new(next_region.start_x + inverted_x, next_region.end_y);
+                    let new_direction = Direction4::Up;
+
+                    return (new_position, new_direction);
+                }
+                _ => {
+                    panic!("This should not happen!");
+                }
+            }
+        }
+        if current_region == face_3 {
+            match current_direction {
+                Direction4::Up => {
+                    let next_region = face_1;
+
+                    let new_position = Point2::new(next_region.start_x, next_region.start_y + normalized_x);
+                    let new_direction = Direction4::Right;
+
+                    return (new_position, new_direction);
+                }
+                Direction4::Down => {
+                    let next_region = face_5;
+
+                    let new_position = Point2::new(next_region.start_x, next_region.start_y + inverted_x);
+                    let new_direction = Direction4::Right;
+
+                    return (new_position, new_direction);
+                }
+                _ => {
+                    panic!("This should not happen!");
+                }
+            }
+        }
+        if current_region == face_4 {
+            match current_direction {
+                Direction4::Right => {
+                    let next_region = face_6;
+
+                    let new_position = Point2::new(next_region.start_x + inverted_y, next_region.start_y);
+                    let new_direction = Direction4::Down;
+
+                    return (new_position, new_direction);
+                }
+                _ => {
+                    panic!("This should not happen!");
+                }
+            }
+        }
+        if current_region == face_5 {
+            match current_direction {
+                Direction4::Left => {
+                    let next_region = face_3;
+
+                    let new_position = Point2::new(next_region.start_x + inverted_y, next_region.end_y);
+                    let new_direction = Direction4::Up;
+
+                    return (new_position, new_direction);
+                }
+                Direction4::Down => {
+                    let next_region = face_2;
+
+                    let new_position = Point2::new(next_region.start_x + inverted_x, next_region.end_y);
+                    let new_direction = Direction4::Up;
+
+                    return (new_position, new_direction);
+                }
+                _ => {
+                    panic!("This should not happen!");
+                }
+            }
+        }
+        if current_region == face_6 {
+            match current_direction {
+                Direction4::Up => {
+                    let next_region = face_4;
+
+                    let new_position = Point2::new(next_region.end_x, next_region.start_y + inverted_y);
+                    let new_direction = Direction4::Left;
+
+                    return (new_position, new_direction);
+                }
+                Direction4::Right => {
+                    let next_region = face_1;
+
+                    let new_position = Point2::new(next_region.end_x, next_region.start_y + inverted_y);
+                    let new_direction = Direction4::Left;
+
+                    return (new_position, new_direction);
+                }
+                Direction4::Down => {
+                    let next_region = face_2;
+
+                    let new_position = Point2::new(next_region.start_x, next_region.start_y + inverted_x);
+                    let new_direction = Direction4::Right;
+
+                    return (new_position, new_direction);
+                }
+                _ => {
+                    panic!("This should not happen!");
+                }
+            }
+        }
+    }
+
+    if current_region == face_1 {
+        match current_direction {
+            Direction4::Left => {
+                let next_region = face_4;
+
+                let new_position = Point2::new(next_region.start_x, next_region.start_y + inverted_y);
+                let new_direction = Direction4::Right;
+
+                return (new_position, new_direction);
+            }
+            Direction4::Up => {
+                let next_region = face_6;
+
+                let new_position = Point2::new(next_region.start_x, next_region.start_y + normalized_x);
+                let new_direction = Direction4::Right;
+
+                return (new_position, new_direction);
+            }
+            _ => {
+                panic!("This should not happen!");
+            }
+        }
+    }
+    if current_region == face_2 {
+        match current_direction {
+            Direction4::Up => {
+                let next_region = face_6;
+
+                let new_position = Point2::new(next_region.start_x + normalized_x, next_region.end_y);
+                let new_direction = Direction4::Up;
+
+                return (new_position, new_direction);
+            }
+            Direction4::Right => {
+                let next_region = face_5;
+
+                let new_position = Point2::new(next_region.end_x, next_region.start_y + inverted_y);
+                let new_direction = Direction4::Left;
+
+                return (new_position, new_direction);
+            }
+            Direction4::Down => {
+                let next_region = face_3;
+
+                let new_position = Point2::new(next_region.end_x, next_region.start_y + normalized_x);
+                let new_direction = Direction4::Left;
+
+                return (new_position, new_direction);
+            }
+            _ => {
+                panic!("This should not happen!");
+            }
+        }
+    }
+    if current_region == face_3 {
+        match current_direction {
+            Direction4::Left => {
+                let next_region = face_4;
+
+                let new_position = Point2::new(next_region.start_x + normalized_y, next_region.start_y);
+                let new_direction = Direction4::Down;
+
+                return (new_position, new_direction);
+            }
+            Direction4::Right => {
+                let next_region = face_2;
+
+                let new_position = Point2::new(next_region.start_x + normalized_y, next_region.end_y);
+                let new_direction = Direction4::Up;
+
+                return (new_position, new_direction);
+            }
+            _ => {
+                panic!("This should not happen!");
+            }
+        }
+    }
+    if current_region == face_4 {
+        match current_direction {
+            Direction4::Left => {
+                let next_region = face_1;
+
+                let new_position = Point2::new(next_region.start_x, next_region.start_y + inverted_y);
+                let new_direction = Direction4::Right;
+
+                return (new_position, new_direction);
+            }
+            Direction4::Up => {
+                let next_region = face_3;
+
+                let new_position = Point2::new(next_region.start_x, next_region.start_y + normalized_x);
+                let new_direction = Direction4::Right;
+
+                return (new_position, new_direction);
+            }
+            _ => {
+                panic!("This should not happen!");
+            }
+        }
+    }
+    if current_region == face_5 {
+        match current_direction {
+            Direction4::Right => {
+                let next_region = face_2;
+
+                let new_position = Point2::new(next_region.end_x, next_region.start_y + inverted_y);
+                let new_direction = Direction4::Left;
+
+                return (new_position, new_direction);
+            }
+            Direction4::Down => {
+                let next_region = face_6;
+
+                let new_position = Point2::new(next_region.end_x, next_region.start_y + normalized_x);
+                let new_direction = Direction4::Left;
+
+                return (new_position, new_direction);
+            }
+            _ => {
+                panic!("This should not happen!");
+            }
+        }
+    }
+    if current_region == face_6 {
+        match current_direction {
+            Direction4::Left => {
+                let next_region = face_1;
+
+                let new_position = Point2::new(next_region.start_x + normalized_y, next_region.start_y);
+                let new_direction = Direction4::Down;
+
+                return (new_position, new_direction);
+            }
+            Direction4::Right => {
+                let next_region = face_5;
+
+                let new_position = Point2::new(next_region.start_x + normalized_y, next_region.end_y);
+                let new_direction = Direction4::Up;
+
+                return (new_position, new_direction);
+            }
+            Direction4::Down => {
+                let next_region = face_2;
+
+                let new_position = Point2::new(next_region.start_x + normalized_x, next_region.start_y);
+                let new_direction = Direction4::Down;
+
+                return (new_position, new_direction);
+            }
+            _ => {
+                panic!("This should not happen!");
+            }
+        }
+    }
+
+    panic!("This should not happen!");
+}
+
+fn extract_regions(map: &Map, region_size: u8) -> Regions {
+    const NUMBER_OF_REGIONS: usize = 6;
+
+    let mut regions = Regions::new();
+
+    let mut current_x = 0;
+    let mut current_y = 0;
+
+    while regions.len() < NUMBER_OF_REGIONS {
+        let tile = map.get(Point2::new(current_x, current_y)).and_then(Option::as_ref);
+
+        if tile.is_none() {
+            current_x += region_size as X;
+
+            let max_x_on_row = map.row(current_y).unwrap().rposition(Option::is_some).unwrap();
+
+            if current_x > max_x_on_row {
+                current_x = 0;
+                current_y += region_size as Y;
+            }
+
+            continue;
+        }
+
+        let region = Region {
+            start_x: current_x,
+            start_y: current_y,
+            end_x: (current_x + region_size as X - 1),
+            end_y: (current_y + region_size as Y - 1),
+        };
+
+        regions.push(region);
+
+        current_x += region_size as X;
+    }
+
+    regions
+}
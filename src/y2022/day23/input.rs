@@ -0,0 +1,35 @@
+use crate::{
+    geometry::Point2,
+    grid::{self, SparseGrid},
+    parse_error::{self, ParseError},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Elf;
+
+pub type Position = grid::SparsePosition;
+
+/// Positions of the elves, the rest of the ground being empty
+pub type Map = SparseGrid<Elf>;
+
+pub type Input = Map;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut map = Map::new();
+
+    for line in parse_error::lines(input) {
+        for (x, char) in line.text.chars().enumerate() {
+            let position = Point2::new(x as i64, line.index as i64);
+
+            match char {
+                '.' => {}
+                '#' => {
+                    map.insert(position, Elf);
+                }
+                _ => return Err(line.error_at(x, "expected . or #")),
+            };
+        }
+    }
+
+    Ok(map)
+}
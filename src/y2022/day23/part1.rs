@@ -0,0 +1,119 @@
+use super::input::{Elf, Input, Map, Position};
+use crate::geometry::{Direction4, Direction8};
+use std::collections::{HashMap, VecDeque};
+
+type MovePropositions = HashMap<Position, Direction4>;
+
+type NextPositionAppearances = HashMap<Position, u8>;
+
+type MovePreferences = VecDeque<Direction4>;
+
+const ROUNDS: u8 = 10;
+
+pub type PuzzleResult = u16;
+
+pub fn get_result(input: &Input) -> PuzzleResult {
+    let mut map = input.clone();
+    let mut move_preferences = initialize_move_preferences();
+
+    for _ in 0..ROUNDS {
+        // first half
+        let move_propositions = get_move_propositions(&map, &move_preferences);
+        let next_position_appearances = get_next_position_appearances(&move_propositions);
+
+        // second half
+        let move_propositions =
+            get_single_move_propositions(&move_propositions, &next_position_appearances);
+
+        for (position, move_proposition) in move_propositions {
+            let new_position = position + move_proposition.offset();
+
+            map.remove(position);
+            map.insert(new_position, Elf);
+        }
+
+        let current_move_preference = move_preferences.pop_front().unwrap();
+        move_preferences.push_back(current_move_preference);
+    }
+
+    let (min, max) = map.bounds().unwrap();
+
+    let width = (max.x - min.x + 1) as u16;
+    let height = (max.y - min.y + 1) as u16;
+
+    let total_area = width * height;
+    let number_of_elves = map.len() as u16;
+
+    total_area - number_of_elves
+}
+
+fn initialize_move_preferences() -> MovePreferences {
+    MovePreferences::from([
+        Direction4::Up,
+        Direction4::Down,
+        Direction4::Left,
+        Direction4::Right,
+    ])
+}
+
+fn get_move_propositions(map: &Map, move_preferences: &MovePreferences) -> MovePropositions {
+    let mut propositions = MovePropositions::new();
+
+    for elf_position in map.positions() {
+        let has_at_least_one_elf_nearby = map
+            .neighbours8(elf_position)
+            .any(|position| map.contains(position));
+
+        if !has_at_least_one_elf_nearby {
+            continue;
+        }
+
+        for move_preference in move_preferences {
+            // the direction of the move along with its two diagonals
+            let direction = Direction8::from(*move_preference);
+            let positions_to_check = [direction, direction.turn_left(), direction.turn_right()]
+                .map(|direction| elf_position + direction.offset());
+
+            let can_move = positions_to_check
+                .iter()
+                .all(|position| !map.contains(*position));
+            if can_move {
+                propositions.insert(elf_position, *move_preference);
+                break;
+            }
+        }
+    }
+
+    propositions
+}
+
+fn get_next_position_appearances(move_propositions: &MovePropositions) -> NextPositionAppearances {
+    let mut appearances = NextPositionAppearances::new();
+
+    for (position, proposition) in move_propositions {
+        let next_position = *position + proposition.offset();
+
+        let appearance = appearances.entry(next_position).or_insert(0);
+        *appearance += 1;
+    }
+
+    appearances
+}
+
+fn get_single_move_propositions(
+    move_propositions: &MovePropositions,
+    next_position_appearances: &NextPositionAppearances,
+) -> MovePropositions {
+    let mut single_move_propositions = MovePropositions::new();
+
+    for (position, move_proposition) in move_propositions {
+        let next_position = *position + move_proposition.offset();
+
+        let appearance = next_position_appearances.get(&next_position).unwrap();
+        if appearance == &1 {
+            single_move_propositions.insert(*position, *move_proposition);
+        }
+    }
+
+    single_move_propositions
+}
@@ -0,0 +1,66 @@
+use crate::{
+    geometry::Direction4,
+    grid::{self, Grid},
+    parse_error::ParseError,
+};
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BlizzardDirections {
+    pub left: bool,
+    pub right: bool,
+    pub up: bool,
+    pub down: bool,
+}
+
+impl BlizzardDirections {
+    pub fn get(&self, direction: Direction4) -> bool {
+        match direction {
+            Direction4::Left => self.left,
+            Direction4::Right => self.right,
+            Direction4::Up => self.up,
+            Direction4::Down => self.down,
+        }
+    }
+
+    pub fn get_mut(&mut self, direction: Direction4) -> &mut bool {
+        match direction {
+            Direction4::Left => &mut self.left,
+            Direction4::Right => &mut self.right,
+            Direction4::Up => &mut self.up,
+            Direction4::Down => &mut self.down,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub enum Tile {
+    Wall,
+    #[default]
+    Ground,
+    Blizzards(BlizzardDirections),
+}
+
+pub type Position = grid::Position;
+
+pub type Map = Grid<Tile>;
+
+pub type Input = Map;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    Grid::parse(input, "expected #, ., <, >, ^ or v", |char| match char {
+        '#' => Some(Tile::Wall),
+        '.' => Some(Tile::Ground),
+        '<' => Some(create_blizzard_tile(Direction4::Left)),
+        '>' => Some(create_blizzard_tile(Direction4::Right)),
+        '^' => Some(create_blizzard_tile(Direction4::Up)),
+        'v' => Some(create_blizzard_tile(Direction4::Down)),
+        _ => None,
+    })
+}
+
+pub fn create_blizzard_tile(direction: Direction4) -> Tile {
+    let mut directions = BlizzardDirections::default();
+    *directions.get_mut(direction) = true;
+
+    Tile::Blizzards(directions)
+}
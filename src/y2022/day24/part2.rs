@@ -0,0 +1,18 @@
+use super::{
+    input::Input,
+    valley::{create_valley, cross_valley, find_entrance_and_exit},
+};
+
+pub type PuzzleResult = u32;
+
+pub fn get_result(initial_map: &Input) -> PuzzleResult {
+    let (initial_position, target_position) = find_entrance_and_exit(initial_map);
+
+    let valley = create_valley(initial_map);
+
+    let mut minutes = cross_valley(&valley, initial_position, target_position, 0);
+    minutes += cross_valley(&valley, target_position, initial_position, minutes);
+    minutes += cross_valley(&valley, initial_position, target_position, minutes);
+
+    minutes as PuzzleResult
+}
@@ -0,0 +1,142 @@
+use super::input::{create_blizzard_tile, Map, Position, Tile};
+use crate::{
+    geometry::{Direction4, Point2},
+    pathfinding::{self, Graph},
+};
+use std::iter;
+
+/// Position of the expedition at a minute of the blizzards period
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Expedition {
+    position: Position,
+    minute: usize,
+}
+
+/// Map of the valley at each minute, the blizzards being back to their initial positions after a full period
+pub struct Valley {
+    maps: Vec<Map>,
+}
+
+impl Graph for Valley {
+    type Node = Expedition;
+
+    /// Move to the next position (or wait)
+    fn neighbours(&self, expedition: &Expedition) -> Vec<Expedition> {
+        let minute = (expedition.minute + 1) % self.maps.len();
+        let map = &self.maps[minute];
+
+        iter::once(expedition.position)
+            .chain(map.neighbours(expedition.position))
+            .filter(|position| map[*position] == Tile::Ground)
+            .map(|position| Expedition { position, minute })
+            .collect()
+    }
+}
+
+/// Positions of the entrance (the gap in the top wall) and of the exit (the gap in the bottom wall)
+pub fn find_entrance_and_exit(map: &Map) -> (Position, Position) {
+    let min_y = 0;
+    let max_y = map.height() - 1;
+
+    let find_gap = |y: usize| {
+        map.row(y)
+            .unwrap()
+            .position(|tile| tile == &Tile::Ground)
+            .unwrap()
+    };
+
+    (
+        Point2::new(find_gap(min_y), min_y),
+        Point2::new(find_gap(max_y), max_y),
+    )
+}
+
+pub fn create_valley(initial_map: &Map) -> Valley {
+    // blizzards wrap around inside the walls
+    let period = least_common_multiple(initial_map.width() - 2, initial_map.height() - 2);
+
+    let mut maps = vec![initial_map.clone()];
+
+    while maps.len() < period {
+        let map = alter_map(maps.last().unwrap());
+        maps.push(map);
+    }
+
+    Valley { maps }
+}
+
+/// Minutes needed to go from one position to another, leaving at the given minute
+pub fn cross_valley(valley: &Valley, from: Position, to: Position, minute: usize) -> usize {
+    let start = Expedition {
+        position: from,
+        minute: minute % valley.maps.len(),
+    };
+
+    let search = pathfinding::bfs(valley, [start], |expedition| expedition.position == to);
+
+    search.target_distance().unwrap() as usize
+}
+
+fn least_common_multiple(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    a / x * b
+}
+
+/// Move all blizzards from the previous map to the current map
+fn alter_map(previous_map: &Map) -> Map {
+    let mut map = previous_map.clone();
+
+    map = remove_blizzards(&map);
+
+    for (position, tile) in previous_map.iter() {
+        if let Tile::Blizzards(directions) = tile {
+            for direction in Direction4::ALL {
+                if !directions.get(direction) {
+                    continue;
+                }
+
+                let new_position = get_next_blizzard_position(position, previous_map, direction);
+
+                match &mut map[new_position] {
+                    Tile::Blizzards(directions) => *directions.get_mut(direction) = true,
+                    tile => *tile = create_blizzard_tile(direction),
+                }
+            }
+        }
+    }
+
+    map
+}
+
+fn get_next_blizzard_position(position: Position, map: &Map, direction: Direction4) -> Position {
+    let new_position = map.neighbour(position, direction).unwrap();
+
+    if map[new_position] != Tile::Wall {
+        return new_position;
+    }
+
+    // wrap around by going back to the opposite wall
+    let mut wrapped_position = position;
+
+    while let Some(previous_position) = map
+        .neighbour(wrapped_position, direction.opposite())
+        .filter(|previous_position| map[*previous_position] != Tile::Wall)
+    {
+        wrapped_position = previous_position;
+    }
+
+    wrapped_position
+}
+
+/// Remove blizzard tiles on current map
+fn remove_blizzards(map: &Map) -> Map {
+    map.map(|tile| match tile {
+        Tile::Blizzards(_) => Tile::Ground,
+        _ => tile.clone(),
+    })
+}
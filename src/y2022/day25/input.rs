@@ -1,3 +1,4 @@
+use crate::parse_error::{self, ParseError};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy)]
@@ -13,20 +14,23 @@ pub type SnafuIndex = u8;
 pub type SnafuNumber = HashMap<SnafuIndex, SnafuUnit>;
 pub type SnafuNumberList = Vec<SnafuNumber>;
 
-pub fn parse_input(input: &str) -> SnafuNumberList {
+pub type Input = SnafuNumberList;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
     let mut list = SnafuNumberList::new();
 
-    for line in input.lines() {
+    for line in parse_error::lines(input) {
         let mut number = SnafuNumber::new();
+        let length = line.text.chars().count();
 
-        for (index, char) in line.chars().rev().enumerate() {
+        for (index, char) in line.text.chars().rev().enumerate() {
             let unit = match char {
                 '2' => SnafuUnit::Two,
                 '1' => SnafuUnit::One,
                 '0' => SnafuUnit::Zero,
                 '-' => SnafuUnit::Minus,
                 '=' => SnafuUnit::DoubleMinus,
-                _ => panic!("Invalid character: {}", char),
+                _ => return Err(line.error_at(length - 1 - index, "expected 2, 1, 0, - or =")),
             };
 
             number.insert(index as SnafuIndex, unit);
@@ -35,5 +39,5 @@ pub fn parse_input(input: &str) -> SnafuNumberList {
         list.push(number);
     }
 
-    list
+    Ok(list)
 }
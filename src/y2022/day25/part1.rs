@@ -1,25 +1,13 @@
-use super::input::{SnafuIndex, SnafuNumber, SnafuUnit};
+use super::input::{Input, SnafuIndex, SnafuNumber, SnafuUnit};
 use itertools::Itertools;
 
 type DecimalNumber = i64;
 
 const SNAFU_BASE: DecimalNumber = 5;
 
-type PuzzleResult = &'static str;
-const EXPECTED_SAMPLE_RESULT: PuzzleResult = "2=-1=0";
-
-pub fn run(input: &str, use_sample: bool) {
-    let result = get_result(input);
-
-    println!("Result: {}", result);
-    if use_sample {
-        assert_eq!(result, EXPECTED_SAMPLE_RESULT);
-    }
-}
-
-fn get_result(input: &str) -> String {
-    let snafu_numbers = super::input::parse_input(input);
+pub type PuzzleResult = String;
 
+pub fn get_result(snafu_numbers: &Input) -> PuzzleResult {
     let numbers = snafu_numbers
         .iter()
         .map(convert_to_decimal)
@@ -28,9 +16,8 @@ fn get_result(input: &str) -> String {
     let total = numbers.iter().sum::<DecimalNumber>();
 
     let snafu_result = convert_to_snafu(total);
-    let snafu_result = get_snafu_number_display(&snafu_result);
 
-    snafu_result
+    get_snafu_number_display(&snafu_result)
 }
 
 fn convert_to_decimal(snafu_number: &SnafuNumber) -> DecimalNumber {
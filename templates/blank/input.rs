@@ -0,0 +1,14 @@
+use crate::parse_error::{self, ParseError};
+
+pub type Input = Vec<i64>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut numbers = Vec::new();
+
+    for line in parse_error::lines(input) {
+        let number = line.parse::<_>(line.text)?;
+        numbers.push(number);
+    }
+
+    Ok(numbers)
+}
@@ -0,0 +1,7 @@
+use super::input::Input;
+
+pub type PuzzleResult = {{result_type}};
+
+pub fn get_result(_numbers: &Input) -> PuzzleResult {
+    PuzzleResult::default()
+}
@@ -0,0 +1,7 @@
+use super::input::Input;
+
+pub type PuzzleResult = u64;
+
+pub fn get_result(_numbers: &Input) -> PuzzleResult {
+    PuzzleResult::default()
+}
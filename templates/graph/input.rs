@@ -0,0 +1,27 @@
+use crate::parse_error::{self, ParseError};
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Neighbours of each node
+pub type Input = HashMap<String, Vec<String>>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let line_regex = Regex::new(r"^(\w+)\W*(.*)$").unwrap();
+    let node_regex = Regex::new(r"\w+").unwrap();
+
+    let mut graph = HashMap::new();
+
+    for line in parse_error::lines(input) {
+        let captures = line.captures(&line_regex)?;
+
+        let node = captures[1].to_string();
+        let neighbours = node_regex
+            .find_iter(&captures[2])
+            .map(|neighbour| neighbour.as_str().to_string())
+            .collect();
+
+        graph.insert(node, neighbours);
+    }
+
+    Ok(graph)
+}
@@ -0,0 +1,15 @@
+use super::input::Input;
+use crate::pathfinding;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(graph: &Input) -> PuzzleResult {
+    let start = match graph.keys().min() {
+        Some(start) => start,
+        None => return 0,
+    };
+
+    let search = pathfinding::bfs(graph, [start.clone()], |_| false);
+
+    search.distances().count()
+}
@@ -0,0 +1,7 @@
+use crate::{grid::Grid, parse_error::ParseError};
+
+pub type Input = Grid<char>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    Grid::parse(input, "expected a cell", Some)
+}
@@ -0,0 +1,12 @@
+use super::input::Input;
+
+pub type PuzzleResult = usize;
+
+pub fn get_result(grid: &Input) -> PuzzleResult {
+    grid.iter()
+        .filter(|(position, cell)| {
+            grid.neighbours8(*position)
+                .any(|neighbour| grid[neighbour] == **cell)
+        })
+        .count()
+}
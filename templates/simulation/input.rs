@@ -0,0 +1,29 @@
+use crate::parse_error::{self, ParseError};
+use regex::Regex;
+
+pub struct Instruction {
+    pub name: String,
+    pub value: Option<i32>,
+}
+
+pub type Input = Vec<Instruction>;
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let regex = Regex::new(r"^(\w+)(?: (-?\d+))?$").unwrap();
+
+    let mut instructions = Vec::new();
+
+    for line in parse_error::lines(input) {
+        let captures = line.captures(&regex)?;
+
+        let name = captures[1].to_string();
+        let value = match captures.get(2) {
+            Some(value) => Some(line.parse(value.as_str())?),
+            None => None,
+        };
+
+        instructions.push(Instruction { name, value });
+    }
+
+    Ok(instructions)
+}
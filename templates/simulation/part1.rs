@@ -0,0 +1,25 @@
+use super::input::{Input, Instruction};
+
+pub type PuzzleResult = i32;
+
+struct State {
+    register: i32,
+}
+
+impl State {
+    fn execute(&mut self, instruction: &Instruction) {
+        if let ("add", Some(value)) = (instruction.name.as_str(), instruction.value) {
+            self.register += value;
+        }
+    }
+}
+
+pub fn get_result(instructions: &Input) -> PuzzleResult {
+    let mut state = State { register: 0 };
+
+    for instruction in instructions {
+        state.execute(instruction);
+    }
+
+    state.register
+}